@@ -9,4 +9,9 @@ rustup default "$MIRI_NIGHTLY"
 rustup component add miri
 cargo miri setup
 
-MIRIFLAGS='-Zmiri-strict-provenance' cargo miri test --all-features
+# `--all-features` would pull in integrations that link to system libraries.
+# Miri runs on nightly, so the nightly-only `ptr_metadata` feature is checked too.
+FEATURES="std serde ptr_metadata"
+
+MIRIFLAGS='-Zmiri-strict-provenance' cargo miri test --features "$FEATURES"
+MIRIFLAGS='-Zmiri-strict-provenance -Zmiri-tree-borrows' cargo miri test --lib --features "$FEATURES"
@@ -0,0 +1,146 @@
+//! `#[derive(Beef)]` for `#[repr(transparent)]` newtypes, re-exported by `beef` with
+//! the `derive` feature. See `beef::Beef` for details.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Implements `beef::Transparent`, and with it `beef::generic::Beef`, for a
+/// `#[repr(transparent)]` struct with a single field.
+///
+/// Enums, unions, other `repr`s and structs with more than one field, zero-sized ones
+/// included, are rejected, as the generated impl relies on pointers to the struct and
+/// its field being interchangeable.
+///
+/// By default, the derive doesn't require an empty field, such as `""`, to be a valid
+/// value of the struct, and `Cow::to_mut` clones owned data of derived types on every
+/// call, so that invariants like `struct NonEmpty(str)` hold even if the guard leaks.
+/// Add `#[beef(empty_is_valid)]` to promise that an empty field is valid, which lets
+/// `Cow::to_mut` leave one behind instead. The field must then be `str`, `[T]` or
+/// `CStr`, or any other type with a `Default` reference.
+///
+/// ```compile_fail
+/// #[derive(beef::Beef)]
+/// struct Ident(str);
+/// ```
+#[proc_macro_derive(Beef, attributes(beef))]
+pub fn derive_beef(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !is_repr_transparent(&input)? {
+        return Err(Error::new(
+            Span::call_site(),
+            "#[derive(Beef)] requires #[repr(transparent)]",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "#[derive(Beef)] can only be used on structs",
+            ))
+        }
+    };
+
+    let inner = match fields {
+        Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0].ty,
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "#[derive(Beef)] requires a struct with exactly one field",
+            ))
+        }
+    };
+
+    let empty = if empty_is_valid(&input)? {
+        quote! {
+            #[inline]
+            fn empty<'a>() -> ::core::option::Option<&'a Self> {
+                ::core::option::Option::Some(<Self as ::beef::Steak>::from_inner_ref(
+                    ::core::default::Default::default(),
+                ))
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::beef::Transparent for #name #ty_generics #where_clause {
+            type Inner = #inner;
+
+            #[inline]
+            fn into_inner(
+                owned: <Self as ::beef::__private::ToOwned>::Owned,
+            ) -> <#inner as ::beef::__private::ToOwned>::Owned {
+                ::core::convert::Into::into(owned)
+            }
+
+            #[inline]
+            fn from_inner(
+                owned: <#inner as ::beef::__private::ToOwned>::Owned,
+            ) -> <Self as ::beef::__private::ToOwned>::Owned {
+                ::core::convert::From::from(owned)
+            }
+
+            #empty
+        }
+    })
+}
+
+fn is_repr_transparent(input: &DeriveInput) -> syn::Result<bool> {
+    let mut transparent = false;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("transparent") {
+                transparent = true;
+            } else if meta.input.peek(syn::token::Paren) {
+                let _args;
+                syn::parenthesized!(_args in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(transparent)
+}
+
+fn empty_is_valid(input: &DeriveInput) -> syn::Result<bool> {
+    let mut empty_is_valid = false;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("beef"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("empty_is_valid") {
+                empty_is_valid = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown #[beef] attribute"))
+            }
+        })?;
+    }
+
+    Ok(empty_is_valid)
+}
@@ -0,0 +1,160 @@
+use beef::Transparent;
+use std::borrow::Borrow;
+
+#[derive(beef::Beef, Debug, PartialEq)]
+#[repr(transparent)]
+struct Ident(str);
+
+#[derive(Clone, Debug, PartialEq)]
+struct IdentBuf(String);
+
+impl Ident {
+    fn new(ident: &str) -> &Ident {
+        unsafe { &*(ident as *const str as *const Ident) }
+    }
+}
+
+impl Borrow<Ident> for IdentBuf {
+    fn borrow(&self) -> &Ident {
+        Ident::new(&self.0)
+    }
+}
+
+impl ToOwned for Ident {
+    type Owned = IdentBuf;
+
+    fn to_owned(&self) -> IdentBuf {
+        IdentBuf(self.0.to_owned())
+    }
+}
+
+impl From<String> for IdentBuf {
+    fn from(s: String) -> Self {
+        IdentBuf(s)
+    }
+}
+
+impl From<IdentBuf> for String {
+    fn from(ident: IdentBuf) -> Self {
+        ident.0
+    }
+}
+
+#[derive(beef::Beef, Debug, PartialEq)]
+#[repr(transparent)]
+#[beef(empty_is_valid)]
+struct Path<T: Clone> {
+    segments: [T],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct PathBuf<T>(Vec<T>);
+
+impl<T: Clone> Path<T> {
+    fn new(segments: &[T]) -> &Path<T> {
+        unsafe { &*(segments as *const [T] as *const Path<T>) }
+    }
+}
+
+impl<T: Clone> Borrow<Path<T>> for PathBuf<T> {
+    fn borrow(&self) -> &Path<T> {
+        Path::new(&self.0)
+    }
+}
+
+impl<T: Clone> ToOwned for Path<T> {
+    type Owned = PathBuf<T>;
+
+    fn to_owned(&self) -> PathBuf<T> {
+        PathBuf(self.segments.to_vec())
+    }
+}
+
+impl<T> From<Vec<T>> for PathBuf<T> {
+    fn from(v: Vec<T>) -> Self {
+        PathBuf(v)
+    }
+}
+
+impl<T> From<PathBuf<T>> for Vec<T> {
+    fn from(path: PathBuf<T>) -> Self {
+        path.0
+    }
+}
+
+fn assert_transparent<T: Transparent + ?Sized>() {}
+
+#[test]
+fn implements_transparent() {
+    assert_transparent::<Ident>();
+    assert_transparent::<Path<u8>>();
+}
+
+#[test]
+fn wide_cow_str_newtype() {
+    let borrowed: beef::Cow<Ident> = beef::Cow::borrowed(Ident::new("foo"));
+    let owned: beef::Cow<Ident> = beef::Cow::owned(IdentBuf("bar".to_string()));
+
+    assert!(borrowed.is_borrowed());
+    assert!(owned.is_owned());
+    assert_eq!(&*borrowed, Ident::new("foo"));
+    assert_eq!(&*owned, Ident::new("bar"));
+    assert_eq!(borrowed.into_owned(), IdentBuf("foo".to_string()));
+    assert_eq!(owned.into_owned(), IdentBuf("bar".to_string()));
+}
+
+#[test]
+fn lean_cow_str_newtype() {
+    let borrowed: beef::lean::Cow<Ident> = beef::lean::Cow::borrowed(Ident::new("foo"));
+    let owned: beef::lean::Cow<Ident> = beef::lean::Cow::owned(IdentBuf("bar".to_string()));
+
+    assert!(borrowed.is_borrowed());
+    assert!(owned.is_owned());
+    assert_eq!(&*borrowed, Ident::new("foo"));
+    assert_eq!(&*owned, Ident::new("bar"));
+    assert_eq!(owned.clone().into_owned(), IdentBuf("bar".to_string()));
+}
+
+#[test]
+fn wide_cow_slice_newtype() {
+    let borrowed: beef::Cow<Path<u8>> = beef::Cow::borrowed(Path::new(&[1, 2, 3]));
+    let owned: beef::Cow<Path<u8>> = beef::Cow::owned(PathBuf(vec![4, 5]));
+
+    assert!(borrowed.is_borrowed());
+    assert!(owned.is_owned());
+    assert_eq!(&borrowed.segments, &[1, 2, 3]);
+    assert_eq!(owned.into_owned(), PathBuf(vec![4, 5]));
+}
+
+#[test]
+fn lean_cow_slice_newtype() {
+    let owned: beef::lean::Cow<Path<u8>> = beef::lean::Cow::owned(PathBuf(vec![4, 5]));
+
+    assert!(owned.is_owned());
+    assert_eq!(&owned.segments, &[4, 5]);
+    assert_eq!(owned.into_owned(), PathBuf(vec![4, 5]));
+}
+
+#[test]
+fn leaked_to_mut_keeps_the_value() {
+    let mut owned: beef::Cow<Ident> = beef::Cow::owned(IdentBuf("foo".to_string()));
+
+    core::mem::forget(owned.to_mut());
+
+    assert_eq!(&*owned, Ident::new("foo"));
+}
+
+#[test]
+fn to_mut_in_place_when_empty_is_valid() {
+    let mut owned: beef::Cow<Path<u8>> = beef::Cow::owned(PathBuf(vec![1, 2]));
+    let ptr = owned.segments.as_ptr();
+
+    owned.to_mut().0.push(3);
+
+    assert_eq!(owned.segments.as_ptr(), ptr);
+    assert_eq!(&owned.segments, &[1, 2, 3]);
+
+    core::mem::forget(owned.to_mut());
+
+    assert!(owned.segments.is_empty());
+}
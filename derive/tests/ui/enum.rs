@@ -0,0 +1,7 @@
+#[derive(beef::Beef)]
+#[repr(transparent)]
+enum Ident {
+    Name(String),
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(beef::Beef)]
+struct Ident(str);
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[derive(beef::Beef)]
+#[repr(transparent)]
+struct Ident((), str);
+
+fn main() {}
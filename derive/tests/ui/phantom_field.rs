@@ -0,0 +1,11 @@
+use std::marker::PhantomData;
+
+// `repr(transparent)` allows extra zero-sized fields, but the derive doesn't
+#[derive(beef::Beef)]
+#[repr(transparent)]
+struct Ident<T> {
+    marker: PhantomData<T>,
+    name: str,
+}
+
+fn main() {}
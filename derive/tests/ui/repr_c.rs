@@ -0,0 +1,5 @@
+#[derive(beef::Beef)]
+#[repr(C)]
+struct Ident(str);
+
+fn main() {}
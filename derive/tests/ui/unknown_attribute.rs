@@ -0,0 +1,6 @@
+#[derive(beef::Beef)]
+#[repr(transparent)]
+#[beef(empty)]
+struct Ident(str);
+
+fn main() {}
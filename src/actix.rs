@@ -0,0 +1,83 @@
+use alloc::borrow::Cow as StdCow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use actix_web::body::EitherBody;
+use actix_web::{HttpRequest, HttpResponse, Responder};
+
+use crate::generic::{Capacity, Cow};
+
+// Borrowed data is served as a static body, owned buffers are handed over
+// without copying.
+impl<U> Responder for Cow<'static, str, U>
+where
+    U: Capacity,
+{
+    type Body = EitherBody<&'static str, String>;
+
+    #[inline]
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        match StdCow::from(self) {
+            StdCow::Borrowed(val) => val.respond_to(req).map_into_left_body(),
+            StdCow::Owned(val) => val.respond_to(req).map_into_right_body(),
+        }
+    }
+}
+
+impl<U> Responder for Cow<'static, [u8], U>
+where
+    U: Capacity,
+{
+    type Body = EitherBody<&'static [u8], Vec<u8>>;
+
+    #[inline]
+    fn respond_to(self, req: &HttpRequest) -> HttpResponse<Self::Body> {
+        match StdCow::from(self) {
+            StdCow::Borrowed(val) => val.respond_to(req).map_into_left_body(),
+            StdCow::Owned(val) => val.respond_to(req).map_into_right_body(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::body::MessageBody;
+    use actix_web::test::TestRequest;
+    use actix_web::Responder;
+
+    #[test]
+    fn wide_cow_str_responder() {
+        use crate::Cow;
+
+        let req = TestRequest::default().to_http_request();
+
+        for cow in [Cow::borrowed("Hello"), Cow::owned("Hello".to_owned())] {
+            let res = cow.respond_to(&req);
+
+            assert_eq!(res.status(), 200);
+            assert_eq!(
+                res.headers().get("content-type").unwrap(),
+                "text/plain; charset=utf-8"
+            );
+            assert_eq!(res.into_body().try_into_bytes().unwrap(), "Hello");
+        }
+    }
+
+    #[test]
+    fn lean_cow_bytes_responder() {
+        use crate::lean::Cow;
+
+        let req = TestRequest::default().to_http_request();
+
+        for cow in [Cow::borrowed(&b"Hello"[..]), Cow::owned(b"Hello".to_vec())] {
+            let res = cow.respond_to(&req);
+
+            assert_eq!(res.status(), 200);
+            assert_eq!(
+                res.headers().get("content-type").unwrap(),
+                "application/octet-stream"
+            );
+            assert_eq!(res.into_body().try_into_bytes().unwrap(), &b"Hello"[..]);
+        }
+    }
+}
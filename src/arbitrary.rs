@@ -0,0 +1,98 @@
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::generic::{Capacity, Cow};
+use crate::traits::Beef;
+
+// Picks between a borrowed and an owned `Cow` with a `bool` taken from the input, so
+// that fuzz targets exercise both.
+#[inline]
+fn arbitrary_cow<'a, T, U>(owned: bool, value: &'a T) -> Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    if owned {
+        Cow::owned(value.to_owned())
+    } else {
+        Cow::borrowed(value)
+    }
+}
+
+impl<'a, U> Arbitrary<'a> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let owned = u.arbitrary()?;
+
+        Ok(arbitrary_cow(owned, <&str>::arbitrary(u)?))
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let owned = u.arbitrary()?;
+
+        Ok(arbitrary_cow(owned, <&str>::arbitrary_take_rest(u)?))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(bool::size_hint(depth), <&str>::size_hint(depth))
+    }
+}
+
+impl<'a, U> Arbitrary<'a> for Cow<'a, [u8], U>
+where
+    U: Capacity,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let owned = u.arbitrary()?;
+
+        Ok(arbitrary_cow(owned, <&[u8]>::arbitrary(u)?))
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let owned = u.arbitrary()?;
+
+        Ok(arbitrary_cow(owned, <&[u8]>::arbitrary_take_rest(u)?))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(bool::size_hint(depth), <&[u8]>::size_hint(depth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn wide_cow_arbitrary() {
+        use crate::Cow;
+
+        let cow = Cow::<str>::arbitrary_take_rest(Unstructured::new(b"\0foo")).unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_borrowed());
+
+        let cow = Cow::<str>::arbitrary_take_rest(Unstructured::new(b"\x01foo")).unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn lean_cow_arbitrary() {
+        use crate::lean::Cow;
+
+        let cow = Cow::<[u8]>::arbitrary_take_rest(Unstructured::new(&[0, 1, 2])).unwrap();
+
+        assert_eq!(cow, &[1, 2][..]);
+        assert!(cow.is_borrowed());
+
+        let cow = Cow::<[u8]>::arbitrary_take_rest(Unstructured::new(&[1, 1, 2])).unwrap();
+
+        assert_eq!(cow, &[1, 2][..]);
+        assert!(cow.is_owned());
+    }
+}
@@ -0,0 +1,157 @@
+//! Helpers for viewing [Apache Arrow](https://docs.rs/arrow-array) string and binary
+//! arrays as borrowed `Cow`s, and for building such arrays from `Cow`s.
+//!
+//! ```rust
+//! use arrow_array::StringArray;
+//! use beef::Cow;
+//!
+//! let array = StringArray::from(vec![Some("Hello"), None, Some("World")]);
+//! let cows: Vec<Option<Cow<str>>> = beef::arrow::str_values(&array).collect();
+//!
+//! assert_eq!(cows[0].as_deref(), Some("Hello"));
+//! assert!(cows[0].as_ref().unwrap().is_borrowed());
+//!
+//! let rebuilt: StringArray = beef::arrow::str_array(cows);
+//!
+//! assert_eq!(array, rebuilt);
+//! ```
+
+use arrow_array::{GenericBinaryArray, GenericStringArray, OffsetSizeTrait};
+
+use crate::generic::{Capacity, Cow};
+
+/// Borrow the value at index `i` of a string array.
+///
+/// # Panics
+///
+/// Panics if `i` is out of bounds.
+#[inline]
+pub fn str_value<O, U>(array: &GenericStringArray<O>, i: usize) -> Cow<'_, str, U>
+where
+    O: OffsetSizeTrait,
+    U: Capacity,
+{
+    Cow::borrowed(array.value(i))
+}
+
+/// Iterate over the values of a string array as borrowed `Cow`s, yielding `None` for nulls.
+#[inline]
+pub fn str_values<O, U>(
+    array: &GenericStringArray<O>,
+) -> impl Iterator<Item = Option<Cow<'_, str, U>>>
+where
+    O: OffsetSizeTrait,
+    U: Capacity,
+{
+    array.iter().map(|val| val.map(Cow::borrowed))
+}
+
+/// Borrow the value at index `i` of a binary array.
+///
+/// # Panics
+///
+/// Panics if `i` is out of bounds.
+#[inline]
+pub fn binary_value<O, U>(array: &GenericBinaryArray<O>, i: usize) -> Cow<'_, [u8], U>
+where
+    O: OffsetSizeTrait,
+    U: Capacity,
+{
+    Cow::borrowed(array.value(i))
+}
+
+/// Iterate over the values of a binary array as borrowed `Cow`s, yielding `None` for nulls.
+#[inline]
+pub fn binary_values<O, U>(
+    array: &GenericBinaryArray<O>,
+) -> impl Iterator<Item = Option<Cow<'_, [u8], U>>>
+where
+    O: OffsetSizeTrait,
+    U: Capacity,
+{
+    array.iter().map(|val| val.map(Cow::borrowed))
+}
+
+/// Build a string array from an iterator of optional `Cow`s. Values are copied
+/// straight into the array buffer, without intermediate `String` allocations.
+#[inline]
+pub fn str_array<'a, O, U, I>(iter: I) -> GenericStringArray<O>
+where
+    O: OffsetSizeTrait,
+    U: Capacity,
+    I: IntoIterator<Item = Option<Cow<'a, str, U>>>,
+{
+    iter.into_iter().collect()
+}
+
+/// Build a binary array from an iterator of optional `Cow`s. Values are copied
+/// straight into the array buffer, without intermediate `Vec` allocations.
+#[inline]
+pub fn binary_array<'a, O, U, I>(iter: I) -> GenericBinaryArray<O>
+where
+    O: OffsetSizeTrait,
+    U: Capacity,
+    I: IntoIterator<Item = Option<Cow<'a, [u8], U>>>,
+{
+    iter.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{BinaryArray, LargeStringArray, StringArray};
+
+    #[test]
+    fn wide_str_values() {
+        use crate::Cow;
+
+        let array = StringArray::from(vec![Some("foo"), None, Some("bar")]);
+        let cows: Vec<Option<Cow<str>>> = super::str_values(&array).collect();
+
+        assert_eq!(cows.len(), 3);
+        assert_eq!(cows[0].as_ref().unwrap(), "foo");
+        assert!(cows[1].is_none());
+        assert_eq!(cows[2].as_ref().unwrap(), "bar");
+        assert!(cows.iter().flatten().all(Cow::is_borrowed));
+
+        let value: Cow<str> = super::str_value(&array, 2);
+
+        assert_eq!(value, "bar");
+    }
+
+    #[test]
+    fn lean_str_array() {
+        use crate::lean::Cow;
+
+        let cows = vec![
+            Some(Cow::borrowed("foo")),
+            None,
+            Some(Cow::owned("bar".to_owned())),
+        ];
+        let array: LargeStringArray = super::str_array(cows);
+
+        assert_eq!(
+            array,
+            LargeStringArray::from(vec![Some("foo"), None, Some("bar")])
+        );
+    }
+
+    #[test]
+    fn wide_binary_roundtrip() {
+        use crate::Cow;
+
+        let array = BinaryArray::from(vec![Some(&b"foo"[..]), None, Some(b"")]);
+        let cows: Vec<Option<Cow<[u8]>>> = super::binary_values(&array).collect();
+
+        assert_eq!(cows[0].as_ref().unwrap(), &b"foo"[..]);
+        assert!(cows[1].is_none());
+        assert!(cows[2].as_ref().unwrap().is_empty());
+
+        let value: Cow<[u8]> = super::binary_value(&array, 0);
+
+        assert_eq!(value, &b"foo"[..]);
+
+        let rebuilt: BinaryArray = super::binary_array(cows);
+
+        assert_eq!(array, rebuilt);
+    }
+}
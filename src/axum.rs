@@ -0,0 +1,52 @@
+use alloc::borrow::Cow as StdCow;
+
+use axum::response::{IntoResponse, Response};
+
+use crate::generic::{Capacity, Cow};
+
+// Going through `std::borrow::Cow` keeps static borrows zero-copy and hands
+// owned buffers over to the body without reallocating.
+impl<U> IntoResponse for Cow<'static, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn into_response(self) -> Response {
+        StdCow::from(self).into_response()
+    }
+}
+
+impl<U> IntoResponse for Cow<'static, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn into_response(self) -> Response {
+        StdCow::from(self).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::response::IntoResponse;
+
+    #[test]
+    fn wide_cow_str_response() {
+        use crate::Cow;
+
+        let res = Cow::borrowed("Hello").into_response();
+
+        assert_eq!(res.status(), 200);
+        assert_eq!(res.headers()["content-type"], "text/plain; charset=utf-8");
+    }
+
+    #[test]
+    fn lean_cow_bytes_response() {
+        use crate::lean::Cow;
+
+        let res = Cow::<[u8]>::owned(b"Hello".to_vec()).into_response();
+
+        assert_eq!(res.status(), 200);
+        assert_eq!(res.headers()["content-type"], "application/octet-stream");
+    }
+}
@@ -0,0 +1,64 @@
+use alloc::borrow::ToOwned;
+
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::generic::{Capacity, Cow};
+use crate::traits::Beef;
+
+impl<T, U> BorshSerialize for Cow<'_, T, U>
+where
+    T: Beef + BorshSerialize + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        T::serialize(self.as_ref(), writer)
+    }
+}
+
+impl<T, U> BorshDeserialize for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    T::Owned: BorshDeserialize,
+    U: Capacity,
+{
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        <T as ToOwned>::Owned::deserialize_reader(reader).map(Cow::owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{from_slice, to_vec};
+
+    #[test]
+    fn wide_cow_borsh() {
+        use crate::Cow;
+
+        let cow: Cow<str> = Cow::borrowed("foo");
+        let bytes = to_vec(&cow).unwrap();
+
+        assert_eq!(bytes, to_vec("foo").unwrap());
+
+        let cow: Cow<str> = from_slice(&bytes).unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn lean_cow_borsh() {
+        use crate::lean::Cow;
+
+        let cow: Cow<[u32]> = Cow::borrowed(&[1, 2, 3]);
+        let bytes = to_vec(&cow).unwrap();
+
+        assert_eq!(bytes, to_vec(&vec![1u32, 2, 3]).unwrap());
+
+        let cow: Cow<[u32]> = from_slice(&bytes).unwrap();
+
+        assert_eq!(cow, &[1, 2, 3][..]);
+    }
+}
@@ -0,0 +1,136 @@
+//! Conversions between `Cow`s and [BSON](https://docs.rs/bson) values.
+//!
+//! `Cow<str>` and `Cow<[u8]>` convert into `Bson::String` and `Bson::Binary`
+//! respectively, reusing the owned buffer when there is one. The functions in this
+//! module extract borrowed `Cow`s out of `Bson` and `RawBsonRef` values.
+//!
+//! ```rust
+//! use bson::{Bson, RawBsonRef};
+//! use beef::Cow;
+//!
+//! let bson = Bson::from(Cow::borrowed("Hello"));
+//! let cow: Cow<str> = beef::bson::as_str(&bson).unwrap();
+//!
+//! assert_eq!(cow, "Hello");
+//! assert!(cow.is_borrowed());
+//!
+//! let raw = RawBsonRef::String("World");
+//! let cow: Cow<str> = beef::bson::raw_as_str(raw).unwrap();
+//!
+//! assert_eq!(cow, "World");
+//! ```
+
+use bson::raw::RawBsonRef;
+use bson::spec::BinarySubtype;
+use bson::{Binary, Bson};
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> From<Cow<'_, str, U>> for Bson
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        Bson::String(cow.into_owned())
+    }
+}
+
+impl<U> From<Cow<'_, [u8], U>> for Bson
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, [u8], U>) -> Self {
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: cow.into_owned(),
+        })
+    }
+}
+
+/// Borrow the string out of a `Bson::String`, returning `None` for any other variant.
+#[inline]
+pub fn as_str<U>(bson: &Bson) -> Option<Cow<'_, str, U>>
+where
+    U: Capacity,
+{
+    bson.as_str().map(Cow::borrowed)
+}
+
+/// Borrow the bytes out of a `Bson::Binary`, returning `None` for any other variant.
+/// The binary subtype is ignored.
+#[inline]
+pub fn as_bytes<U>(bson: &Bson) -> Option<Cow<'_, [u8], U>>
+where
+    U: Capacity,
+{
+    match bson {
+        Bson::Binary(binary) => Some(Cow::borrowed(&binary.bytes)),
+        _ => None,
+    }
+}
+
+/// Borrow the string out of a `RawBsonRef::String`, returning `None` for any other
+/// variant. The `Cow` borrows straight from the raw document.
+#[inline]
+pub fn raw_as_str<U>(raw: RawBsonRef<'_>) -> Option<Cow<'_, str, U>>
+where
+    U: Capacity,
+{
+    raw.as_str().map(Cow::borrowed)
+}
+
+/// Borrow the bytes out of a `RawBsonRef::Binary`, returning `None` for any other
+/// variant. The binary subtype is ignored, and the `Cow` borrows straight from the
+/// raw document.
+#[inline]
+pub fn raw_as_bytes<U>(raw: RawBsonRef<'_>) -> Option<Cow<'_, [u8], U>>
+where
+    U: Capacity,
+{
+    raw.as_binary().map(|binary| Cow::borrowed(binary.bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use bson::raw::RawBsonRef;
+    use bson::{doc, Bson, RawDocumentBuf};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn wide_cow_into_bson() {
+        use crate::Cow;
+
+        let bson = Bson::from(Cow::<str>::owned("foo".to_owned()));
+
+        assert_eq!(bson, Bson::String("foo".to_owned()));
+
+        let bson = Bson::from(Cow::<[u8]>::borrowed(b"bar"));
+        let cow: Cow<[u8]> = super::as_bytes(&bson).unwrap();
+
+        assert_eq!(cow, &b"bar"[..]);
+        assert!(cow.is_borrowed());
+
+        let none: Option<Cow<str>> = super::as_str(&bson);
+
+        assert!(none.is_none());
+    }
+
+    #[test]
+    fn lean_cow_from_raw() {
+        use crate::lean::Cow;
+
+        let doc = doc! { "foo": "bar" };
+        let raw = RawDocumentBuf::try_from(&doc).unwrap();
+        let value: RawBsonRef = raw.get("foo").unwrap().unwrap();
+        let cow: Cow<str> = super::raw_as_str(value).unwrap();
+
+        assert_eq!(cow, "bar");
+        assert!(cow.is_borrowed());
+
+        let none: Option<Cow<[u8]>> = super::raw_as_bytes(value);
+
+        assert!(none.is_none());
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> Cow<'static, [u8], U>
+where
+    U: Capacity,
+{
+    /// Converts into `Bytes` without copying. Borrowed data becomes a static `Bytes`,
+    /// owned data has its buffer moved into the `Bytes`.
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let bytes = Cow::borrowed(&b"Hello"[..]).into_bytes();
+    ///
+    /// assert_eq!(bytes, &b"Hello"[..]);
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Bytes {
+        match self.try_unwrap_borrowed() {
+            Ok(borrowed) => Bytes::from_static(borrowed),
+            Err(owned) => Bytes::from(owned.into_owned()),
+        }
+    }
+}
+
+impl<U> From<Cow<'static, [u8], U>> for Bytes
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'static, [u8], U>) -> Self {
+        cow.into_bytes()
+    }
+}
+
+// Owned buffers are moved into the `BytesMut`, borrowed slices are copied.
+impl<U> From<Cow<'_, [u8], U>> for BytesMut
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, [u8], U>) -> Self {
+        match cow.try_unwrap_borrowed() {
+            Ok(borrowed) => BytesMut::from(borrowed),
+            Err(owned) => BytesMut::from(Bytes::from(owned.into_owned())),
+        }
+    }
+}
+
+// The buffer is reused when the `BytesMut` is its only handle and starts at the
+// beginning of it, otherwise the bytes are copied.
+impl<U> From<BytesMut> for Cow<'_, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(bytes: BytesMut) -> Self {
+        Cow::owned(Vec::from(bytes))
+    }
+}
+
+// Same as with `BytesMut`, a uniquely owned `Bytes` gives up its buffer, a shared or
+// static one is copied.
+impl<U> From<Bytes> for Cow<'_, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        Cow::owned(Vec::from(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Bytes, BytesMut};
+
+    #[test]
+    fn wide_cow_into_bytes() {
+        use crate::Cow;
+
+        let cow: Cow<[u8]> = Cow::owned(b"Hello".to_vec());
+        let ptr = cow.as_ptr();
+        let bytes = cow.into_bytes();
+
+        assert_eq!(bytes, &b"Hello"[..]);
+        assert_eq!(bytes.as_ptr(), ptr.as_ptr());
+
+        let bytes = Bytes::from(Cow::<[u8]>::borrowed(b"World"));
+
+        assert_eq!(bytes, &b"World"[..]);
+
+        let mut buf = BytesMut::from(Cow::<[u8]>::owned(b"Hello".to_vec()));
+
+        buf.extend_from_slice(b", World!");
+
+        assert_eq!(buf, &b"Hello, World!"[..]);
+    }
+
+    #[test]
+    fn lean_cow_from_bytes() {
+        use crate::lean::Cow;
+
+        let mut buf = BytesMut::with_capacity(64);
+
+        buf.extend_from_slice(b"Hello");
+
+        let ptr = buf.as_ptr();
+        let cow = Cow::<[u8]>::from(buf);
+
+        assert_eq!(cow, &b"Hello"[..]);
+        assert!(cow.is_owned());
+        assert_eq!((*cow).as_ptr(), ptr);
+
+        let cow = Cow::<[u8]>::from(Bytes::from_static(b"World"));
+
+        assert_eq!(cow, &b"World"[..]);
+    }
+}
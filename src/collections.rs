@@ -0,0 +1,493 @@
+//! Collections keyed by `Cow`s.
+//!
+//! [`CowMap`](./struct.CowMap.html) is a `HashMap` with `Cow<'static, str>` keys that
+//! is looked up with plain `&str`s, and only allocates a key when an entry is
+//! actually inserted. It is backed by `hashbrown`, which can insert a key built
+//! after the lookup, without hashing it again.
+//!
+//! ```rust
+//! use beef::collections::CowMap;
+//!
+//! let mut headers = CowMap::new();
+//!
+//! headers.insert("content-type", "text/plain");
+//! *headers.entry("x-request-id").or_insert("") = "1234";
+//!
+//! assert_eq!(headers.get("content-type"), Some(&"text/plain"));
+//! assert_eq!(headers["x-request-id"], "1234");
+//! ```
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FromIterator;
+use core::ops::Index;
+use hashbrown::hash_map::{self, EntryRef, HashMap};
+use std::collections::hash_map::RandomState;
+
+use crate::generic::IntoCow;
+use crate::Cow;
+
+/// A `HashMap` with `Cow<'static, str>` keys and allocate-on-insert `&str` lookups.
+///
+/// See the [module documentation](./index.html) for an example.
+pub struct CowMap<V, S = RandomState> {
+    map: HashMap<Cow<'static, str>, V, S>,
+}
+
+impl<V> CowMap<V> {
+    /// Creates an empty `CowMap`.
+    #[inline]
+    pub fn new() -> Self {
+        CowMap {
+            map: HashMap::with_hasher(RandomState::new()),
+        }
+    }
+
+    /// Creates an empty `CowMap` with space for at least `capacity` entries.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        CowMap {
+            map: HashMap::with_capacity_and_hasher(capacity, RandomState::new()),
+        }
+    }
+}
+
+impl<V, S> CowMap<V, S> {
+    /// Creates an empty `CowMap` which will use the given hash builder.
+    #[inline]
+    pub fn with_hasher(hasher: S) -> Self {
+        CowMap {
+            map: HashMap::with_hasher(hasher),
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Removes all entries, keeping the allocated memory for reuse.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> hash_map::Iter<'_, Cow<'static, str>, V> {
+        self.map.iter()
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    #[inline]
+    pub fn keys(&self) -> hash_map::Keys<'_, Cow<'static, str>, V> {
+        self.map.keys()
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    #[inline]
+    pub fn values(&self) -> hash_map::Values<'_, Cow<'static, str>, V> {
+        self.map.values()
+    }
+
+    /// Returns a reference to the underlying `hashbrown::HashMap`.
+    #[inline]
+    pub fn as_map(&self) -> &HashMap<Cow<'static, str>, V, S> {
+        &self.map
+    }
+
+    /// Unwraps the underlying `hashbrown::HashMap`.
+    #[inline]
+    pub fn into_map(self) -> HashMap<Cow<'static, str>, V, S> {
+        self.map
+    }
+}
+
+impl<V, S> CowMap<V, S>
+where
+    S: BuildHasher,
+{
+    /// Returns a reference to the value for `key`.
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.map.get(key)
+    }
+
+    /// Returns a mutable reference to the value for `key`.
+    #[inline]
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.map.get_mut(key)
+    }
+
+    /// Returns `true` if the map contains a value for `key`.
+    #[inline]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Inserts a value, returning the previous value for the key, if any.
+    ///
+    /// The key is hashed once, and only copied into an owned `Cow` if it isn't
+    /// already present. Use [`insert_cow`](#method.insert_cow) to store a static or
+    /// owned key without copying it.
+    #[inline]
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    /// Inserts a value, returning the previous value for the key, if any.
+    ///
+    /// Static strings and owned `String`s are stored without copying. If the key is
+    /// already present the existing key is kept and the new one is dropped.
+    #[inline]
+    pub fn insert_cow(&mut self, key: impl IntoCow<'static, str>, value: V) -> Option<V> {
+        self.map.insert(key.into_cow(), value)
+    }
+
+    /// Removes a key from the map, returning its value if it was present.
+    #[inline]
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        self.map.remove(key)
+    }
+
+    /// Gets the entry for `key`, for in-place manipulation.
+    ///
+    /// The key is hashed once, and only copied into an owned `Cow` if a vacant entry
+    /// is filled.
+    #[inline]
+    pub fn entry<'k>(&mut self, key: &'k str) -> Entry<'_, 'k, V, S> {
+        match self.map.entry_ref(key) {
+            EntryRef::Occupied(entry) => Entry::Occupied(OccupiedEntry {
+                value: entry.into_mut(),
+            }),
+            EntryRef::Vacant(entry) => Entry::Vacant(VacantEntry { entry }),
+        }
+    }
+}
+
+/// A view into a single entry of a [`CowMap`](./struct.CowMap.html).
+pub enum Entry<'a, 'k, V, S> {
+    /// The key is present in the map.
+    Occupied(OccupiedEntry<'a, V>),
+    /// The key is not present in the map.
+    Vacant(VacantEntry<'a, 'k, V, S>),
+}
+
+/// An occupied entry of a [`CowMap`](./struct.CowMap.html).
+pub struct OccupiedEntry<'a, V> {
+    value: &'a mut V,
+}
+
+/// A vacant entry of a [`CowMap`](./struct.CowMap.html), holding the borrowed key.
+pub struct VacantEntry<'a, 'k, V, S> {
+    entry: hash_map::VacantEntryRef<'a, 'k, Cow<'static, str>, str, V, S>,
+}
+
+impl<'a, V, S> Entry<'a, '_, V, S>
+where
+    S: BuildHasher,
+{
+    /// Inserts `default` if the entry is vacant, and returns a mutable reference to the value.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a mutable
+    /// reference to the value.
+    #[inline]
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts the default value if the entry is vacant, and returns a mutable
+    /// reference to the value.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Modifies the value in place if the entry is occupied.
+    #[inline]
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Entry::Occupied(ref mut entry) = self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, V> OccupiedEntry<'a, V> {
+    /// Returns a reference to the value.
+    #[inline]
+    pub fn get(&self) -> &V {
+        self.value
+    }
+
+    /// Returns a mutable reference to the value.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        self.value
+    }
+
+    /// Converts the entry into a mutable reference to the value.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        self.value
+    }
+
+    /// Replaces the value, returning the old one.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.value, value)
+    }
+}
+
+impl<'a, 'k, V, S> VacantEntry<'a, 'k, V, S>
+where
+    S: BuildHasher,
+{
+    /// Returns the borrowed key.
+    #[inline]
+    pub fn key(&self) -> &'k str {
+        self.entry.key()
+    }
+
+    /// Copies the key into the map and inserts the value, returning a mutable
+    /// reference to it. The hash computed by [`CowMap::entry`](./struct.CowMap.html#method.entry)
+    /// is reused.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        let key = Cow::owned(self.entry.key().into());
+
+        self.entry.insert_with_key(key, value)
+    }
+}
+
+impl<V, S> Clone for CowMap<V, S>
+where
+    V: Clone,
+    S: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        CowMap {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<V, S> Default for CowMap<V, S>
+where
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        CowMap {
+            map: HashMap::default(),
+        }
+    }
+}
+
+impl<V, S> fmt::Debug for CowMap<V, S>
+where
+    V: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.map.fmt(f)
+    }
+}
+
+impl<V, S> PartialEq for CowMap<V, S>
+where
+    V: PartialEq,
+    S: BuildHasher,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<V, S> Eq for CowMap<V, S>
+where
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+impl<Q, V, S> Index<&Q> for CowMap<V, S>
+where
+    Cow<'static, str>: Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// Panics if the key is not present in the map.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        &self.map[key]
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for CowMap<V, S>
+where
+    K: IntoCow<'static, str>,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = CowMap::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for CowMap<V, S>
+where
+    K: IntoCow<'static, str>,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.map
+            .extend(iter.into_iter().map(|(key, value)| (key.into_cow(), value)));
+    }
+}
+
+impl<'a, V, S> IntoIterator for &'a CowMap<V, S> {
+    type Item = (&'a Cow<'static, str>, &'a V);
+    type IntoIter = hash_map::Iter<'a, Cow<'static, str>, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+impl<V, S> IntoIterator for CowMap<V, S> {
+    type Item = (Cow<'static, str>, V);
+    type IntoIter = hash_map::IntoIter<Cow<'static, str>, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CowMap, Entry};
+
+    #[test]
+    fn entry_allocates_on_insert() {
+        let mut map = CowMap::new();
+        let key = String::from("foo");
+
+        *map.entry(&key).or_default() += 1;
+        *map.entry(&key).or_default() += 1;
+        map.insert_cow("bar", 5);
+
+        assert_eq!(map.get("foo"), Some(&2));
+        assert!(map.keys().find(|k| *k == "foo").unwrap().is_owned());
+        assert!(map.keys().find(|k| *k == "bar").unwrap().is_borrowed());
+
+        match map.entry("baz") {
+            Entry::Vacant(entry) => assert_eq!(entry.key(), "baz"),
+            Entry::Occupied(_) => panic!("baz should be vacant"),
+        }
+
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn insert_borrowed_keys() {
+        let mut map = CowMap::new();
+        let key = String::from("foo");
+
+        assert_eq!(map.insert(&key, 1), None);
+        assert_eq!(map.insert(key.as_str(), 2), Some(1));
+        assert_eq!(map.insert_cow("bar", 3), None);
+        assert_eq!(map.insert("bar", 4), Some(3));
+
+        assert_eq!(map["foo"], 2);
+        assert_eq!(map["bar"], 4);
+        assert!(map.keys().find(|k| *k == "foo").unwrap().is_owned());
+        assert!(map.keys().find(|k| *k == "bar").unwrap().is_borrowed());
+    }
+
+    #[test]
+    fn and_modify() {
+        let mut map: CowMap<i32> = vec![("foo", 1)].into_iter().collect();
+
+        map.entry("foo").and_modify(|v| *v *= 10).or_insert(0);
+        map.entry("bar").and_modify(|v| *v *= 10).or_insert(3);
+
+        assert_eq!(map["foo"], 10);
+        assert_eq!(map["bar"], 3);
+        assert_eq!(map.remove("foo"), Some(10));
+        assert!(!map.contains_key("foo"));
+    }
+
+    #[test]
+    fn entry_hashes_once() {
+        use core::cell::Cell;
+        use core::hash::BuildHasher;
+        use std::collections::hash_map::{DefaultHasher, RandomState};
+        use std::rc::Rc;
+
+        struct Counting(Rc<Cell<usize>>, RandomState);
+
+        impl BuildHasher for Counting {
+            type Hasher = DefaultHasher;
+
+            fn build_hasher(&self) -> DefaultHasher {
+                self.0.set(self.0.get() + 1);
+                self.1.build_hasher()
+            }
+        }
+
+        let hashes = Rc::new(Cell::new(0));
+        let mut map = CowMap::with_hasher(Counting(hashes.clone(), RandomState::new()));
+
+        *map.entry("foo").or_insert(0) += 1;
+        assert_eq!(hashes.get(), 1);
+
+        *map.entry("foo").or_insert(0) += 1;
+        assert_eq!(hashes.get(), 2);
+
+        *map.entry("bar").or_insert(0) += 1;
+        assert_eq!(hashes.get(), 3);
+
+        assert_eq!(map["foo"], 2);
+        assert_eq!(map["bar"], 1);
+    }
+}
@@ -0,0 +1,86 @@
+use compact_str::CompactString;
+
+use crate::generic::{Capacity, Cow};
+
+// Strings too long to be inlined keep their heap buffer when converting either way,
+// the same as between `CompactString` and `String`.
+impl<U> From<Cow<'_, str, U>> for CompactString
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        match cow.try_unwrap_borrowed() {
+            Ok(borrowed) => CompactString::new(borrowed),
+            Err(owned) => CompactString::from(owned.into_owned()),
+        }
+    }
+}
+
+// A `CompactString` created from a `&'static str` is borrowed again.
+impl<U> From<CompactString> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(string: CompactString) -> Self {
+        match string.as_static_str() {
+            Some(borrowed) => Cow::borrowed(borrowed),
+            None => Cow::owned(string.into_string()),
+        }
+    }
+}
+
+impl<'a, U> From<&'a CompactString> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(string: &'a CompactString) -> Self {
+        Cow::borrowed(string.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use compact_str::CompactString;
+
+    const LONG: &str = "a string long enough to not be inlined";
+
+    #[test]
+    fn wide_cow_into_compact_str() {
+        use crate::Cow;
+
+        let cow: Cow<str> = Cow::owned(LONG.to_owned());
+        let ptr = cow.as_ptr();
+        let string = CompactString::from(cow);
+
+        assert_eq!(string, LONG);
+        assert_eq!(string.as_ptr(), ptr.as_ptr() as *const u8);
+
+        let string = CompactString::from(Cow::<str>::borrowed("foo"));
+
+        assert_eq!(string, "foo");
+    }
+
+    #[test]
+    fn lean_cow_from_compact_str() {
+        use crate::lean::Cow;
+
+        let string = CompactString::from(LONG.to_owned());
+        let ptr = string.as_ptr();
+        let cow = Cow::<str>::from(string);
+
+        assert_eq!(cow, LONG);
+        assert_eq!((*cow).as_ptr(), ptr);
+
+        let cow = Cow::<str>::from(CompactString::const_new(LONG));
+
+        assert!(cow.is_borrowed());
+
+        let string = CompactString::new("foo");
+        let cow = Cow::<str>::from(&string);
+
+        assert!(cow.is_borrowed());
+    }
+}
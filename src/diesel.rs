@@ -0,0 +1,137 @@
+use alloc::borrow::Cow as StdCow;
+use core::fmt;
+
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql, Queryable};
+use diesel::expression::{AsExpression, TypedExpressionType};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{SingleValue, SqlType};
+
+use crate::generic::{Capacity, Cow};
+use crate::traits::Beef;
+
+// These mirror diesel's impls for `std::borrow::Cow`, so `Cow<str>` works with `Text`
+// and `Cow<[u8]>` with `Binary`, including their `Nullable` counterparts.
+
+impl<T, U, ST, DB> ToSql<ST, DB> for Cow<'_, T, U>
+where
+    T: Beef + ToSql<ST, DB> + ?Sized,
+    U: Capacity,
+    DB: Backend,
+    Self: fmt::Debug,
+{
+    #[inline]
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        T::to_sql(self.as_ref(), out)
+    }
+}
+
+impl<T, U, ST, DB> FromSql<ST, DB> for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    T::Owned: FromSql<ST, DB>,
+    U: Capacity,
+    DB: Backend,
+{
+    #[inline]
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        T::Owned::from_sql(bytes).map(Cow::owned)
+    }
+}
+
+impl<T, U, ST, DB> Queryable<ST, DB> for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+    ST: SingleValue,
+    DB: Backend,
+    Self: FromSql<ST, DB>,
+{
+    type Row = Self;
+
+    #[inline]
+    fn build(row: Self::Row) -> deserialize::Result<Self> {
+        Ok(row)
+    }
+}
+
+impl<'a, T, U, ST> AsExpression<ST> for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+    StdCow<'a, T>: AsExpression<ST>,
+    ST: SqlType + TypedExpressionType,
+{
+    type Expression = <StdCow<'a, T> as AsExpression<ST>>::Expression;
+
+    #[inline]
+    fn as_expression(self) -> Self::Expression {
+        StdCow::from(self).as_expression()
+    }
+}
+
+impl<'b, T, U, ST> AsExpression<ST> for &'b Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+    &'b T: AsExpression<ST>,
+    ST: SqlType + TypedExpressionType,
+{
+    type Expression = <&'b T as AsExpression<ST>>::Expression;
+
+    #[inline]
+    fn as_expression(self) -> Self::Expression {
+        self.as_ref().as_expression()
+    }
+}
+
+#[cfg(all(test, feature = "diesel_pg"))]
+mod tests {
+    use diesel::deserialize::{FromSqlRow, Queryable};
+    use diesel::pg::Pg;
+    use diesel::prelude::*;
+    use diesel::sql_types::{Binary, Nullable, Text};
+
+    diesel::table! {
+        users (id) {
+            id -> Integer,
+            name -> Text,
+            avatar -> Nullable<Binary>,
+        }
+    }
+
+    fn queryable<T: FromSqlRow<ST, Pg> + Queryable<ST, Pg>, ST>() {}
+
+    #[test]
+    fn wide_cow_diesel() {
+        use crate::Cow;
+
+        let name: Cow<str> = Cow::borrowed("foo");
+        let query = users::table.filter(users::name.eq(&name));
+
+        assert_eq!(
+            diesel::debug_query::<Pg, _>(&query).to_string(),
+            r#"SELECT "users"."id", "users"."name", "users"."avatar" FROM "users" WHERE ("users"."name" = $1) -- binds: ["foo"]"#,
+        );
+
+        queryable::<Cow<str>, Text>();
+        queryable::<Option<Cow<[u8]>>, Nullable<Binary>>();
+    }
+
+    #[test]
+    fn lean_cow_diesel() {
+        use crate::lean::Cow;
+
+        let query = diesel::insert_into(users::table).values((
+            users::name.eq(Cow::<str>::owned("foo".to_owned())),
+            users::avatar.eq(Some(Cow::<[u8]>::borrowed(&[1, 2]))),
+        ));
+
+        assert_eq!(
+            diesel::debug_query::<Pg, _>(&query).to_string(),
+            r#"INSERT INTO "users" ("name", "avatar") VALUES ($1, $2) -- binds: ["foo", Some([1, 2])]"#,
+        );
+
+        queryable::<Cow<str>, Text>();
+    }
+}
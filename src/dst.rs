@@ -0,0 +1,360 @@
+use crate::generic::Capacity;
+use crate::traits::internal::InternalBeef;
+use crate::traits::Beef;
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use core::fmt;
+use core::ops::Deref;
+use core::ptr::{self, NonNull, Pointee};
+
+/// A custom dynamically sized type, such as `struct Packet { tag: u32, data: [u8] }`,
+/// stored in a [`Cow`](./struct.Cow.html) through its pointer metadata.
+///
+/// `Dst<T>` is [`Beef`](./generic/trait.Beef.html) for any `T` whose metadata is a
+/// length, and which is owned as a `Box<T>`. To store `T` itself in a `Cow`, implement
+/// [`Steak`](./trait.Steak.html) for it with `Dst<T>` as the inner type, using only
+/// the safe conversions on `Dst`.
+///
+/// There is no empty value of an arbitrary `T`, so for a `Cow<Dst<T>>`,
+/// [`Cow::to_mut`](./generic/struct.Cow.html#method.to_mut) clones owned data. Return
+/// an empty `T` from [`Steak::empty`](./trait.Steak.html#method.empty) to move owned
+/// data out of a `Cow<T>` instead.
+///
+/// Requires the `ptr_metadata` feature, and with it nightly.
+///
+/// ```rust
+/// #![feature(ptr_metadata)]
+/// use beef::{Cow, Dst, Steak};
+/// use std::alloc::{alloc, handle_alloc_error, Layout};
+/// use std::ptr;
+///
+/// #[derive(Debug, PartialEq)]
+/// #[repr(C)]
+/// struct Packet<D: ?Sized = [u8]> {
+///     tag: u32,
+///     data: D,
+/// }
+///
+/// impl ToOwned for Packet {
+///     type Owned = Box<Packet>;
+///
+///     fn to_owned(&self) -> Box<Packet> {
+///         // All fields are `Copy`, so copying the bytes makes a valid clone
+///         unsafe {
+///             let layout = Layout::for_value(self);
+///             let data = alloc(layout);
+///
+///             if data.is_null() {
+///                 handle_alloc_error(layout);
+///             }
+///
+///             ptr::copy_nonoverlapping(self as *const Packet as *const u8, data, layout.size());
+///
+///             Box::from_raw(ptr::from_raw_parts_mut(data, ptr::metadata(self)))
+///         }
+///     }
+/// }
+///
+/// impl Steak for Packet {
+///     type Inner = Dst<Packet>;
+///
+///     fn as_inner(&self) -> &Dst<Packet> {
+///         Dst::new(self)
+///     }
+///
+///     fn from_inner_ref(inner: &Dst<Packet>) -> &Packet {
+///         inner
+///     }
+///
+///     fn into_inner_owned(owned: Box<Packet>) -> Box<Dst<Packet>> {
+///         Dst::from_box(owned)
+///     }
+///
+///     fn from_inner_owned(owned: Box<Dst<Packet>>) -> Box<Packet> {
+///         Dst::into_box(owned)
+///     }
+///
+///     fn empty<'a>() -> Option<&'a Packet> {
+///         static EMPTY: Packet<[u8; 0]> = Packet { tag: 0, data: [] };
+///
+///         Some(&EMPTY)
+///     }
+/// }
+///
+/// let packet: &Packet = &Packet { tag: 7, data: [1, 2, 3] };
+///
+/// let borrowed: Cow<Packet> = Cow::borrowed(packet);
+/// let boxed: Box<Packet> = Box::new(Packet { tag: 7, data: [1, 2, 3] });
+/// let owned: Cow<Packet> = Cow::owned(boxed);
+///
+/// assert!(borrowed.is_borrowed());
+/// assert!(owned.is_owned());
+/// assert_eq!(borrowed.tag, 7);
+/// assert_eq!(&owned.data, &[1, 2, 3]);
+/// assert_eq!(borrowed, owned);
+/// assert_eq!(borrowed.into_owned(), packet.to_owned());
+///
+/// let mut owned = owned;
+/// let ptr = owned.as_ptr();
+///
+/// owned.to_mut().tag = 8;
+///
+/// assert_eq!(owned.tag, 8);
+/// assert_eq!(owned.as_ptr(), ptr);
+/// ```
+#[repr(transparent)]
+pub struct Dst<T: ?Sized>(T);
+
+impl<T: ?Sized> Dst<T> {
+    /// Wraps a reference to `T`.
+    #[inline]
+    pub fn new(value: &T) -> &Dst<T> {
+        unsafe { &*(value as *const T as *const Dst<T>) }
+    }
+
+    /// Wraps a boxed `T`, without reallocating.
+    #[inline]
+    pub fn from_box(value: Box<T>) -> Box<Dst<T>> {
+        unsafe { Box::from_raw(Box::into_raw(value) as *mut Dst<T>) }
+    }
+
+    /// Unwraps a boxed `Dst<T>`, without reallocating.
+    #[inline]
+    pub fn into_box(this: Box<Dst<T>>) -> Box<T> {
+        unsafe { Box::from_raw(Box::into_raw(this) as *mut T) }
+    }
+}
+
+impl<T: ?Sized> Deref for Dst<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for Dst<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> ToOwned for Dst<T>
+where
+    T: ToOwned<Owned = Box<T>> + ?Sized,
+{
+    type Owned = Box<Dst<T>>;
+
+    #[inline]
+    fn to_owned(&self) -> Box<Dst<T>> {
+        Dst::from_box(self.0.to_owned())
+    }
+}
+
+impl<T> Beef for Dst<T> where T: Pointee<Metadata = usize> + ToOwned<Owned = Box<T>> + ?Sized {}
+
+/// The metadata of `T` is stored in place of the length. A `Box` has no spare
+/// capacity, so the capacity is only used to mark the `Cow` as owned.
+///
+/// There is no empty value of an arbitrary `T` to leave behind in the `Cow`, so the
+/// default `empty_parts` is kept, and `Cow::to_mut` clones owned data, unless it is
+/// wrapped in a `Steak` type that provides one.
+unsafe impl<T> InternalBeef for Dst<T>
+where
+    T: Pointee<Metadata = usize> + ToOwned<Owned = Box<T>> + ?Sized,
+{
+    type PointerT = u8;
+
+    #[inline]
+    fn ref_into_parts<U>(&self) -> (NonNull<u8>, usize, U::Field)
+    where
+        U: Capacity,
+    {
+        let (data, metadata) = (self as *const Dst<T>).to_raw_parts();
+        let (fat, cap) = U::empty(metadata);
+
+        // The data pointer is only ever put back together with the same metadata and
+        // turned into a `&Dst<T>`, so it is never written through.
+        (unsafe { NonNull::new_unchecked(data as *mut u8) }, fat, cap)
+    }
+
+    #[inline]
+    unsafe fn ref_from_parts<U>(ptr: NonNull<u8>, fat: usize) -> *const Dst<T>
+    where
+        U: Capacity,
+    {
+        ptr::from_raw_parts(ptr.as_ptr() as *const (), U::len(fat))
+    }
+
+    #[inline]
+    fn owned_into_parts<U>(owned: Box<Dst<T>>) -> (NonNull<u8>, usize, U::Field)
+    where
+        U: Capacity,
+    {
+        let (data, metadata) = Box::into_raw(owned).to_raw_parts();
+        let (fat, cap) = U::store(metadata, 1);
+
+        (unsafe { NonNull::new_unchecked(data as *mut u8) }, fat, cap)
+    }
+
+    #[inline]
+    unsafe fn owned_from_parts<U>(ptr: NonNull<u8>, fat: usize, capacity: U::NonZero) -> Box<Dst<T>>
+    where
+        U: Capacity,
+    {
+        let (metadata, _) = U::unpack(fat, capacity);
+
+        Box::from_raw(ptr::from_raw_parts_mut(ptr.as_ptr() as *mut (), metadata))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dst;
+    use crate::Steak;
+    use alloc::alloc::{alloc, handle_alloc_error, Layout};
+    use alloc::borrow::ToOwned;
+    use alloc::boxed::Box;
+    use core::ptr;
+
+    #[derive(Debug, PartialEq)]
+    #[repr(C)]
+    struct Packet<D: ?Sized = [u8]> {
+        tag: u32,
+        data: D,
+    }
+
+    impl ToOwned for Packet {
+        type Owned = Box<Packet>;
+
+        fn to_owned(&self) -> Box<Packet> {
+            unsafe {
+                let layout = Layout::for_value(self);
+                let data = alloc(layout);
+
+                if data.is_null() {
+                    handle_alloc_error(layout);
+                }
+
+                ptr::copy_nonoverlapping(self as *const Packet as *const u8, data, layout.size());
+
+                Box::from_raw(ptr::from_raw_parts_mut(data, ptr::metadata(self)))
+            }
+        }
+    }
+
+    impl Steak for Packet {
+        type Inner = Dst<Packet>;
+
+        fn as_inner(&self) -> &Dst<Packet> {
+            Dst::new(self)
+        }
+
+        fn from_inner_ref(inner: &Dst<Packet>) -> &Packet {
+            inner
+        }
+
+        fn into_inner_owned(owned: Box<Packet>) -> Box<Dst<Packet>> {
+            Dst::from_box(owned)
+        }
+
+        fn from_inner_owned(owned: Box<Dst<Packet>>) -> Box<Packet> {
+            Dst::into_box(owned)
+        }
+
+        fn empty<'a>() -> Option<&'a Packet> {
+            static EMPTY: Packet<[u8; 0]> = Packet { tag: 0, data: [] };
+
+            Some(&EMPTY)
+        }
+    }
+
+    fn packet() -> Box<Packet> {
+        Box::new(Packet {
+            tag: 7,
+            data: [1, 2, 3],
+        })
+    }
+
+    #[test]
+    fn wide_cow_dst() {
+        use crate::Cow;
+
+        let boxed = packet();
+        let borrowed: Cow<Dst<Packet>> = Cow::borrowed(Dst::new(&*boxed));
+        let owned: Cow<Dst<Packet>> = Cow::owned(Dst::from_box(packet()));
+
+        assert!(borrowed.is_borrowed());
+        assert!(owned.is_owned());
+        assert_eq!(borrowed.tag, 7);
+        assert_eq!(&owned.data, &[1, 2, 3]);
+        assert_eq!(Dst::into_box(borrowed.into_owned()), packet());
+    }
+
+    #[test]
+    fn wide_cow_steak_dst() {
+        use crate::Cow;
+
+        let boxed = packet();
+        let borrowed: Cow<Packet> = Cow::borrowed(&boxed);
+        let owned: Cow<Packet> = Cow::owned(packet());
+        let cloned = owned.clone();
+
+        assert!(borrowed.is_borrowed());
+        assert!(cloned.is_owned());
+        assert_eq!(borrowed, owned);
+        assert_eq!(cloned.into_owned(), boxed);
+    }
+
+    #[test]
+    fn wide_cow_dst_leaked_guard() {
+        use crate::Cow;
+
+        let mut owned: Cow<Packet> = Cow::owned(packet());
+        let mut guard = owned.to_mut();
+
+        guard.tag = 8;
+
+        // Drop the data in place, so the test itself doesn't leak memory under Miri
+        unsafe { ptr::drop_in_place::<Box<Packet>>(&mut *guard) };
+        core::mem::forget(guard);
+
+        // The empty packet is left behind
+        assert!(owned.is_borrowed());
+        assert_eq!(owned.tag, 0);
+        assert!(owned.data.is_empty());
+    }
+
+    #[test]
+    fn lean_cow_dst_to_mut() {
+        use crate::lean::Cow;
+
+        let mut owned: Cow<Packet> = Cow::owned(packet());
+        let ptr = owned.as_ptr();
+
+        owned.to_mut().data[0] = 4;
+
+        assert!(owned.is_owned());
+        assert_eq!(owned.as_ptr(), ptr);
+        assert_eq!(owned.tag, 7);
+        assert_eq!(&owned.data, &[4, 2, 3]);
+    }
+
+    #[test]
+    fn lean_cow_steak_dst() {
+        use crate::lean::Cow;
+
+        let boxed = packet();
+        let borrowed: Cow<Packet> = Cow::borrowed(&boxed);
+        let owned: Cow<Packet> = Cow::owned(packet());
+        let cloned = owned.clone();
+
+        assert!(borrowed.is_borrowed());
+        assert!(cloned.is_owned());
+        assert_eq!(borrowed, owned);
+        assert_eq!(&owned.data, &[1, 2, 3]);
+        assert_eq!(cloned.into_owned(), boxed);
+    }
+}
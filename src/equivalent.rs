@@ -0,0 +1,68 @@
+// Lookups of `&str` or `&[T]` keys in maps keyed by `Cow` are already covered by the
+// blanket `Equivalent` impl, since `Cow<T>: Borrow<T>`. These impls cover the other
+// direction, querying maps keyed by `String` or `Vec<T>` with a `&Cow`.
+
+use alloc::{string::String, vec::Vec};
+
+use equivalent::Equivalent;
+
+use crate::generic::{Capacity, Cow};
+use crate::traits::Beef;
+
+impl<U> Equivalent<String> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn equivalent(&self, key: &String) -> bool {
+        self.as_ref() == key.as_str()
+    }
+}
+
+impl<T, U> Equivalent<Vec<T>> for Cow<'_, [T], U>
+where
+    T: Eq,
+    [T]: Beef,
+    U: Capacity,
+{
+    #[inline]
+    fn equivalent(&self, key: &Vec<T>) -> bool {
+        self.as_ref() == key.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::{IndexMap, IndexSet};
+
+    #[test]
+    fn wide_cow_equivalent() {
+        use crate::Cow;
+
+        let mut map: IndexMap<String, u32> = IndexMap::new();
+
+        map.insert("foo".to_owned(), 1);
+
+        assert_eq!(map.get(&Cow::borrowed("foo")), Some(&1));
+        assert_eq!(map.get(&Cow::<str>::owned("foo".to_owned())), Some(&1));
+        assert_eq!(map.get(&Cow::borrowed("bar")), None);
+
+        let mut map: IndexMap<Cow<str>, u32> = IndexMap::new();
+
+        map.insert(Cow::owned("foo".to_owned()), 1);
+
+        assert_eq!(map.get("foo"), Some(&1));
+    }
+
+    #[test]
+    fn lean_cow_equivalent() {
+        use crate::lean::Cow;
+
+        let mut set: IndexSet<Vec<u8>> = IndexSet::new();
+
+        set.insert(b"foo".to_vec());
+
+        assert!(set.contains(&Cow::borrowed(&b"foo"[..])));
+        assert!(!set.contains(&Cow::borrowed(&b"bar"[..])));
+    }
+}
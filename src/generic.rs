@@ -1,24 +1,44 @@
 //! This module contains the actual, albeit generic, implementaiton of the `Cow`,
 //! and the traits that are available to it.
 
-use alloc::borrow::{Borrow, Cow as StdCow};
+use alloc::borrow::{Borrow, Cow as StdCow, ToOwned};
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::ffi::CString;
+use alloc::rc::Rc;
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::ffi::CStr;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
 use core::marker::PhantomData;
 use core::mem::ManuallyDrop;
 use core::ptr::NonNull;
 
 #[cfg(target_pointer_width = "64")]
 use crate::lean::internal::Lean;
-pub use crate::traits::{Beef, Capacity};
+pub use crate::traits::{Beef, Capacity, IntoCow, SplitPattern, Steak, Transparent};
 use crate::wide::internal::Wide;
 
 /// A clone-on-write smart pointer, mostly compatible with [`std::borrow::Cow`](https://doc.rust-lang.org/std/borrow/enum.Cow.html).
 ///
 /// This type is using a generic `U: Capacity`. Use either [`beef::Cow`](../type.Cow.html) or [`beef::lean::Cow`](../lean/type.Cow.html) in your code.
+///
+/// Code that should work with both layouts can be generic over the sealed `Capacity` trait:
+///
+/// ```rust
+/// use beef::generic::{Capacity, Cow};
+///
+/// fn shout<U: Capacity>(cow: &Cow<str, U>) -> String {
+///     cow.to_uppercase()
+/// }
+///
+/// assert_eq!(shout(&beef::Cow::borrowed("wide")), "WIDE");
+/// assert_eq!(shout(&beef::lean::Cow::borrowed("lean")), "LEAN");
+/// ```
 pub struct Cow<'a, T: Beef + ?Sized + 'a, U: Capacity> {
     /// Pointer to data
     ptr: NonNull<T::PointerT>,
@@ -101,6 +121,113 @@ where
         }
     }
 
+    /// Acquires mutable access to the owned data.
+    ///
+    /// Clones the data if it is not already owned. Unlike `std::borrow::Cow`, the owned
+    /// data is not stored as is, so this returns a guard that puts it back into the
+    /// `Cow` when dropped. The guard derefs to `T::Owned`, so it can be passed on
+    /// wherever a `&mut String` or `&mut Vec<T>` is expected.
+    ///
+    /// # Differences from `std::borrow::Cow::to_mut`
+    ///
+    /// This can't return `&mut T::Owned`, as the `Cow` only holds the pointer, length
+    /// and capacity of the owned data, and never a `T::Owned` to borrow from. Instead,
+    /// every call rebuilds the `T::Owned` from those parts, and the guard takes it apart
+    /// again when dropped. No data is copied either way, unless it was borrowed.
+    ///
+    /// While the guard is alive, the `Cow` holds the original borrow if the data was
+    /// borrowed. Owned data is replaced with an empty value, such as `""`, or cloned for
+    /// types that have none, such as [`Steak`](../trait.Steak.html) types that don't
+    /// provide [`Steak::empty`](../trait.Steak.html#method.empty). If the guard is
+    /// leaked, any changes made through it are lost.
+    ///
+    /// # Panics
+    ///
+    /// For the lean `Cow`, dropping the guard panics if the length or capacity of the
+    /// owned data has grown past `u32::MAX`, the same as
+    /// [`Cow::owned`](#method.owned) does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// fn exclaim(s: &mut String) {
+    ///     s.push('!');
+    /// }
+    ///
+    /// let mut cow: Cow<str> = Cow::borrowed("Hello");
+    ///
+    /// cow.to_mut().push_str(" World");
+    /// exclaim(&mut cow.to_mut());
+    ///
+    /// assert_eq!(cow, "Hello World!");
+    /// assert!(cow.is_owned());
+    /// ```
+    #[inline]
+    pub fn to_mut(&mut self) -> OwnedMut<'_, 'a, T, U> {
+        let owned = match (self.capacity(), T::empty_parts::<U>()) {
+            // Leave an empty borrow behind, so leaking the guard can't cause a double free
+            (Some(_), Some((ptr, fat, cap))) => {
+                let empty = Cow {
+                    ptr,
+                    fat,
+                    cap,
+                    marker: PhantomData,
+                };
+
+                core::mem::replace(self, empty).into_owned()
+            }
+            // Borrowed data, or owned data of a type without an empty value, stays where
+            // it is until the guard replaces it with the copy
+            _ => T::to_owned(&**self),
+        };
+
+        OwnedMut {
+            cow: self,
+            owned: ManuallyDrop::new(owned),
+        }
+    }
+
+    /// Replaces the data with the result of `f`, or keeps it as is, borrow included,
+    /// if `f` returns `None`.
+    ///
+    /// This is the building block of helpers that should only allocate if they
+    /// actually change something.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// fn lowercase(cow: Cow<str>) -> Cow<str> {
+    ///     cow.update_or_keep(|s| {
+    ///         if s.chars().any(char::is_uppercase) {
+    ///             Some(s.to_lowercase())
+    ///         } else {
+    ///             None
+    ///         }
+    ///     })
+    /// }
+    ///
+    /// let kept = lowercase(Cow::borrowed("beef"));
+    /// let updated = lowercase(Cow::borrowed("Beef"));
+    ///
+    /// assert!(kept.is_borrowed());
+    /// assert_eq!(updated, "beef");
+    /// assert!(updated.is_owned());
+    /// ```
+    #[inline]
+    pub fn update_or_keep<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T) -> Option<T::Owned>,
+    {
+        match f(self.borrow()) {
+            Some(owned) => Cow::owned(owned),
+            None => self,
+        }
+    }
+
     /// Extracts borrowed data.
     ///
     /// Panics: If the data is owned.
@@ -112,6 +239,29 @@ where
         unsafe { &*T::ref_from_parts::<U>(self.ptr, self.fat) }
     }
 
+    /// Extracts borrowed data, with its original lifetime.
+    ///
+    /// If the data is owned, the `Cow` is returned back as the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let borrowed: Cow<str> = Cow::borrowed("Borrowed");
+    /// let owned: Cow<str> = Cow::owned(String::from("Owned"));
+    ///
+    /// assert_eq!(borrowed.try_unwrap_borrowed(), Ok("Borrowed"));
+    /// assert_eq!(owned.try_unwrap_borrowed().unwrap_err(), "Owned");
+    /// ```
+    #[inline]
+    pub fn try_unwrap_borrowed(self) -> Result<&'a T, Self> {
+        if self.capacity().is_some() {
+            return Err(self);
+        }
+        Ok(unsafe { &*T::ref_from_parts::<U>(self.ptr, self.fat) })
+    }
+
     /// Returns `true` if data is borrowed or had no capacity.
     ///
     /// # Example
@@ -152,6 +302,35 @@ where
         self.capacity().is_some()
     }
 
+    /// Returns `true` if data is borrowed and lies entirely within `source`.
+    ///
+    /// This only compares addresses, so it can be used to check whether a `Cow`
+    /// still references a buffer before that buffer is reused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let buffer = String::from("key=value");
+    /// let key: Cow<str> = Cow::borrowed(&buffer[..3]);
+    /// let copy: Cow<str> = Cow::owned(buffer[..3].to_string());
+    ///
+    /// assert!(key.is_borrowed_from(&buffer));
+    /// assert!(!copy.is_borrowed_from(&buffer));
+    /// assert!(!key.is_borrowed_from("key"));
+    /// ```
+    #[inline]
+    pub fn is_borrowed_from(&self, source: &T) -> bool {
+        let start = source as *const T as *const u8 as usize;
+        let end = start + core::mem::size_of_val(source);
+        let data = self.borrow();
+        let data_start = data as *const T as *const u8 as usize;
+        let data_end = data_start + core::mem::size_of_val(data);
+
+        self.is_borrowed() && data_start >= start && data_end <= end
+    }
+
     /// Internal convenience method for casting `ptr` into a `&T`
     #[inline]
     fn borrow(&self) -> &T {
@@ -247,16 +426,13 @@ impl<'a> Cow<'a, str, Lean> {
             // We are casting *const T to *mut T, however for all borrowed values
             // this raw pointer is only ever dereferenced back to &T.
             ptr: unsafe { NonNull::new_unchecked(val.as_ptr() as *mut u8) },
-            fat: Lean::mask_len(val.len()),
+            fat: Lean::check_len(val.len()),
             cap: Lean,
             marker: PhantomData,
         }
     }
 }
 
-// This requires nightly:
-// https://github.com/rust-lang/rust/issues/57563
-#[cfg(feature = "const_fn")]
 impl<'a, T> Cow<'a, [T], Wide>
 where
     T: Clone,
@@ -285,16 +461,14 @@ where
     }
 }
 
-// This requires nightly:
-// https://github.com/rust-lang/rust/issues/57563
-#[cfg(all(feature = "const_fn", target_pointer_width = "64"))]
+#[cfg(target_pointer_width = "64")]
 impl<'a, T> Cow<'a, [T], Lean>
 where
     T: Clone,
 {
     /// Borrowed data.
     ///
-    /// This i functionally identical to [`borrow`](./generic/struct.Cow.html#method.borrow).
+    /// This is functionally identical to [`borrow`](./generic/struct.Cow.html#method.borrow).
     /// We use impl specialization to allow this function to be `const`.
     ///
     /// # Example
@@ -309,190 +483,1733 @@ where
             // We are casting *const T to *mut T, however for all borrowed values
             // this raw pointer is only ever dereferenced back to &T.
             ptr: unsafe { NonNull::new_unchecked(val.as_ptr() as *mut T) },
-            fat: Lean::mask_len(val.len()),
+            fat: Lean::check_len(val.len()),
             cap: Lean,
             marker: PhantomData,
         }
     }
 }
 
-impl<T, U> Hash for Cow<'_, T, U>
+impl<'a, T, U> Cow<'a, [T], U>
 where
-    T: Hash + Beef + ?Sized,
+    T: Clone,
     U: Capacity,
 {
+    /// Converts into a `Box<[T]>`, dropping any excess capacity.
+    ///
+    /// Owned data is shrunk to fit, borrowed data is copied in a single allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let mut vec = Vec::with_capacity(16);
+    /// vec.extend_from_slice(&[1, 2, 3]);
+    ///
+    /// let boxed: Box<[u8]> = Cow::<[u8]>::owned(vec).into_boxed_slice();
+    ///
+    /// assert_eq!(&*boxed, [1, 2, 3]);
+    /// ```
     #[inline]
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.borrow().hash(state)
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.into()
     }
-}
 
-impl<'a, T, U> Default for Cow<'a, T, U>
-where
-    T: Beef + ?Sized,
-    U: Capacity,
-    &'a T: Default,
-{
+    /// Splits off the first element, returning it together with the rest of the slice,
+    /// or `None` if the slice is empty.
+    ///
+    /// Both are borrowed from the `Cow`, whether its data is borrowed or owned, so this
+    /// is O(1) and never allocates, which makes head/tail recursion linear.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// fn sum(cow: &Cow<[u32]>) -> u32 {
+    ///     match cow.split_first() {
+    ///         Some((head, tail)) => *head + sum(&tail),
+    ///         None => 0,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(sum(&Cow::borrowed(&[1, 2, 3])), 6);
+    /// assert_eq!(sum(&Cow::owned(vec![4, 5, 6])), 15);
+    /// ```
     #[inline]
-    fn default() -> Self {
-        Cow::borrowed(Default::default())
-    }
-}
-
-impl<T, U> Eq for Cow<'_, T, U>
-where
-    T: Eq + Beef + ?Sized,
-    U: Capacity,
-{
-}
+    pub fn split_first(&self) -> Option<(&T, Cow<'_, [T], U>)> {
+        let (first, rest) = self.as_ref().split_first()?;
 
-impl<A, B, U, V> PartialOrd<Cow<'_, B, V>> for Cow<'_, A, U>
-where
-    A: Beef + ?Sized + PartialOrd<B>,
-    B: Beef + ?Sized,
-    U: Capacity,
-    V: Capacity,
-{
-    #[inline]
-    fn partial_cmp(&self, other: &Cow<'_, B, V>) -> Option<Ordering> {
-        PartialOrd::partial_cmp(self.borrow(), other.borrow())
+        Some((first, Cow::borrowed(rest)))
     }
-}
 
-impl<T, U> Ord for Cow<'_, T, U>
-where
-    T: Ord + Beef + ?Sized,
-    U: Capacity,
-{
+    /// Splits off the last element, returning it together with the rest of the slice,
+    /// or `None` if the slice is empty.
+    ///
+    /// Same as [`split_first`](#method.split_first), both are borrowed from the `Cow`,
+    /// so this is O(1) and never allocates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let cow: Cow<[u32]> = Cow::owned(vec![1, 2, 3]);
+    /// let (last, rest) = cow.split_last().unwrap();
+    ///
+    /// assert_eq!(*last, 3);
+    /// assert_eq!(rest, &[1, 2][..]);
+    /// assert!(rest.is_borrowed());
+    /// ```
     #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        Ord::cmp(self.borrow(), other.borrow())
-    }
-}
+    pub fn split_last(&self) -> Option<(&T, Cow<'_, [T], U>)> {
+        let (last, rest) = self.as_ref().split_last()?;
 
-impl<'a, T, U> From<&'a T> for Cow<'a, T, U>
-where
-    T: Beef + ?Sized,
-    U: Capacity,
-{
-    #[inline]
-    fn from(val: &'a T) -> Self {
-        Cow::borrowed(val)
+        Some((last, Cow::borrowed(rest)))
     }
 }
 
-impl<U> From<String> for Cow<'_, str, U>
+impl<'a, U> Cow<'a, str, U>
 where
     U: Capacity,
 {
-    #[inline]
-    fn from(s: String) -> Self {
-        Cow::owned(s)
-    }
-}
+    /// Appends to the string, reserving the exact space needed when it has to be
+    /// copied out of a borrow.
+    fn push_str(&mut self, rhs: &str) {
+        let mut buf = match StdCow::from(core::mem::replace(self, Cow::borrowed(""))) {
+            StdCow::Borrowed(lhs) => {
+                let mut buf = String::with_capacity(lhs.len() + rhs.len());
+                buf.push_str(lhs);
+                buf
+            }
+            StdCow::Owned(buf) => buf,
+        };
 
-impl<T, U> From<Vec<T>> for Cow<'_, [T], U>
-where
-    T: Clone,
-    U: Capacity,
-{
-    #[inline]
-    fn from(v: Vec<T>) -> Self {
-        Cow::owned(v)
+        buf.push_str(rhs);
+        *self = Cow::owned(buf);
     }
-}
 
-impl<T, U> Drop for Cow<'_, T, U>
-where
-    T: Beef + ?Sized,
-    U: Capacity,
-{
+    /// Converts into a `Box<str>`, dropping any excess capacity.
+    ///
+    /// Owned data is shrunk to fit, borrowed data is copied in a single allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let boxed: Box<str> = Cow::borrowed("Hello").into_boxed_str();
+    ///
+    /// assert_eq!(&*boxed, "Hello");
+    /// ```
     #[inline]
-    fn drop(&mut self) {
-        if let Some(capacity) = self.capacity() {
-            unsafe { T::owned_from_parts::<U>(self.ptr, self.fat, capacity) };
-        }
+    pub fn into_boxed_str(self) -> Box<str> {
+        self.into()
     }
-}
 
-impl<'a, T, U> Clone for Cow<'a, T, U>
-where
-    T: Beef + ?Sized,
-    U: Capacity,
-{
-    #[inline]
-    fn clone(&self) -> Self {
-        match self.capacity() {
-            Some(_) => Cow::owned(self.borrow().to_owned()),
-            None => Cow { ..*self },
+    /// Validates bytes as UTF-8, without copying them.
+    ///
+    /// Borrowed bytes produce a borrowed `Cow`, owned bytes keep their allocation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let borrowed = Cow::<str>::from_utf8(Cow::borrowed(&b"Hello"[..])).unwrap();
+    /// let owned = Cow::<str>::from_utf8(Cow::owned(b"World".to_vec())).unwrap();
+    ///
+    /// assert!(borrowed.is_borrowed());
+    /// assert!(owned.is_owned());
+    /// assert!(Cow::<str>::from_utf8(Cow::borrowed(&b"\xFF"[..])).is_err());
+    /// ```
+    pub fn from_utf8(bytes: Cow<'a, [u8], U>) -> Result<Self, core::str::Utf8Error> {
+        match StdCow::from(bytes) {
+            StdCow::Borrowed(bytes) => core::str::from_utf8(bytes).map(Cow::borrowed),
+            StdCow::Owned(bytes) => String::from_utf8(bytes)
+                .map(Cow::owned)
+                .map_err(|err| err.utf8_error()),
         }
     }
-}
 
-impl<T, U> core::ops::Deref for Cow<'_, T, U>
-where
-    T: Beef + ?Sized,
-    U: Capacity,
-{
-    type Target = T;
+    /// Converts bytes to a string, replacing invalid UTF-8 sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// Same as `String::from_utf8_lossy`, this stays borrowed if the bytes are
+    /// already valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let valid: Cow<str> = Cow::from_utf8_lossy(b"Hello");
+    /// let invalid: Cow<str> = Cow::from_utf8_lossy(b"Hello \xF0\x90\x80World");
+    ///
+    /// assert!(valid.is_borrowed());
+    /// assert_eq!(invalid, "Hello \u{FFFD}World");
+    /// ```
+    pub fn from_utf8_lossy(bytes: &'a [u8]) -> Self {
+        Cow::from(String::from_utf8_lossy(bytes))
+    }
 
+    /// Decodes UTF-16 into an owned `Cow`, returning an error if it contains
+    /// invalid data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let music = [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0x0069, 0x0063];
+    /// let cow: Cow<'static, str> = Cow::from_utf16(&music).unwrap();
+    ///
+    /// assert_eq!(cow, "𝄞music");
+    /// assert!(Cow::<str>::from_utf16(&[0xD834, 0x006d]).is_err());
+    /// ```
     #[inline]
-    fn deref(&self) -> &T {
-        self.borrow()
+    pub fn from_utf16(v: &[u16]) -> Result<Self, alloc::string::FromUtf16Error> {
+        String::from_utf16(v).map(Cow::owned)
     }
-}
 
-impl<T, U> AsRef<T> for Cow<'_, T, U>
-where
-    T: Beef + ?Sized,
-    U: Capacity,
-{
+    /// Decodes UTF-16 into an owned `Cow`, replacing invalid data with
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let cow: Cow<str> = Cow::from_utf16_lossy(&[0xD834, 0x006d]);
+    ///
+    /// assert_eq!(cow, "\u{FFFD}m");
+    /// ```
     #[inline]
-    fn as_ref(&self) -> &T {
-        self.borrow()
+    pub fn from_utf16_lossy(v: &[u16]) -> Self {
+        Cow::owned(String::from_utf16_lossy(v))
     }
-}
 
-impl<T, U> Borrow<T> for Cow<'_, T, U>
-where
-    T: Beef + ?Sized,
-    U: Capacity,
-{
-    #[inline]
-    fn borrow(&self) -> &T {
+    /// Formats any `Display` value into an owned `Cow`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Display` implementation returns an error, same as `ToString`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let cow: Cow<str> = Cow::from_display(42);
+    ///
+    /// assert_eq!(cow, "42");
+    /// ```
+    pub fn from_display(val: impl fmt::Display) -> Self {
+        use core::fmt::Write;
+
+        let mut buf = String::new();
+
+        buf.write_fmt(format_args!("{}", val))
+            .expect("a Display implementation returned an error unexpectedly");
+
+        Cow::owned(buf)
+    }
+
+    /// Replaces every run of whitespace with a single space.
+    ///
+    /// Returns the `Cow` unchanged, without allocating, if it contains no runs of
+    /// whitespace or whitespace characters other than a space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let collapsed = Cow::borrowed("Hello  \t World").collapse_whitespace();
+    ///
+    /// assert_eq!(collapsed, "Hello World");
+    /// assert!(collapsed.is_owned());
+    ///
+    /// let unchanged = Cow::borrowed("Hello World").collapse_whitespace();
+    ///
+    /// assert!(unchanged.is_borrowed());
+    /// ```
+    pub fn collapse_whitespace(self) -> Self {
+        self.update_or_keep(|s| {
+            let mut prev = false;
+            let collapsed = s.chars().all(|c| {
+                let space = c.is_whitespace();
+                let keep = !space || (c == ' ' && !prev);
+
+                prev = space;
+                keep
+            });
+
+            if collapsed {
+                return None;
+            }
+
+            let mut out = String::with_capacity(s.len());
+            let mut prev = false;
+
+            for c in s.chars() {
+                if !c.is_whitespace() {
+                    out.push(c);
+                    prev = false;
+                } else if !prev {
+                    out.push(' ');
+                    prev = true;
+                }
+            }
+
+            Some(out)
+        })
+    }
+
+    /// Splits the string on the first match of `pat`, returning the parts before and
+    /// after it, or `None` if there is no match.
+    ///
+    /// Borrowed strings are split into two borrowed `Cow`s. Owned strings are split
+    /// into two owned `Cow`s, reusing the allocation for the first part, so that only
+    /// the second part is allocated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let (key, value) = Cow::borrowed("key=value").split_once('=').unwrap();
+    ///
+    /// assert_eq!(key, "key");
+    /// assert_eq!(value, "value");
+    /// assert!(key.is_borrowed() && value.is_borrowed());
+    ///
+    /// assert!(Cow::borrowed("key").split_once("=>").is_none());
+    /// ```
+    pub fn split_once<P: SplitPattern>(self, pat: P) -> Option<(Self, Self)> {
+        let range = pat.find_in(&self)?;
+
+        Some(self.split_at_match(range))
+    }
+
+    /// Splits the string on the last match of `pat`, returning the parts before and
+    /// after it, or `None` if there is no match.
+    ///
+    /// Allocates the same way as [`split_once`](#method.split_once).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let (path, file) = Cow::borrowed("src/generic.rs").rsplit_once('/').unwrap();
+    ///
+    /// assert_eq!(path, "src");
+    /// assert_eq!(file, "generic.rs");
+    /// ```
+    pub fn rsplit_once<P: SplitPattern>(self, pat: P) -> Option<(Self, Self)> {
+        let range = pat.rfind_in(&self)?;
+
+        Some(self.split_at_match(range))
+    }
+
+    fn split_at_match(self, (start, end): (usize, usize)) -> (Self, Self) {
+        match StdCow::from(self) {
+            StdCow::Borrowed(s) => (Cow::borrowed(&s[..start]), Cow::borrowed(&s[end..])),
+            StdCow::Owned(mut s) => {
+                let tail = String::from(&s[end..]);
+
+                s.truncate(start);
+
+                (Cow::owned(s), Cow::owned(tail))
+            }
+        }
+    }
+
+    /// Keeps only the characters for which `f` returns `true`, calling it once for
+    /// each character, in order.
+    ///
+    /// Returns the `Cow` unchanged, without allocating, if every character passes.
+    /// Owned strings are filtered in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let digits = Cow::borrowed("+1 (555) 010-999").filter_chars(|c| c.is_ascii_digit());
+    ///
+    /// assert_eq!(digits, "1555010999");
+    ///
+    /// let unchanged = Cow::borrowed("1555010999").filter_chars(|c| c.is_ascii_digit());
+    ///
+    /// assert!(unchanged.is_borrowed());
+    /// ```
+    pub fn filter_chars<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(char) -> bool,
+    {
+        let first = match self.char_indices().find(|&(_, c)| !f(c)) {
+            Some((idx, _)) => idx,
+            None => return self,
+        };
+
+        // `f` has already seen everything up to and including `first`, so it is only
+        // called on the rest, exactly once per character.
+        match StdCow::from(self) {
+            StdCow::Borrowed(s) => {
+                let mut out = String::with_capacity(s.len());
+
+                out.push_str(&s[..first]);
+                out.extend(s[first..].chars().skip(1).filter(|&c| f(c)));
+
+                Cow::owned(out)
+            }
+            StdCow::Owned(mut s) => {
+                let mut idx = 0;
+
+                s.retain(|c| {
+                    let at = idx;
+
+                    idx += c.len_utf8();
+
+                    match at.cmp(&first) {
+                        Ordering::Less => true,
+                        Ordering::Equal => false,
+                        Ordering::Greater => f(c),
+                    }
+                });
+
+                Cow::owned(s)
+            }
+        }
+    }
+
+    /// Truncates the string to at most `max_chars` characters, replacing the
+    /// last character that fits with an ellipsis (`…`) if anything was cut off.
+    ///
+    /// Returns the `Cow` unchanged, without allocating, if it already fits.
+    /// Owned strings keep their buffer, which only grows if the 3 byte ellipsis
+    /// doesn't fit in the space freed by truncating.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let truncated = Cow::borrowed("Hello World").truncate_with_ellipsis(8);
+    ///
+    /// assert_eq!(truncated, "Hello W…");
+    ///
+    /// let unchanged = Cow::borrowed("Hello").truncate_with_ellipsis(8);
+    ///
+    /// assert!(unchanged.is_borrowed());
+    /// ```
+    pub fn truncate_with_ellipsis(self, max_chars: usize) -> Self {
+        if self.char_indices().nth(max_chars).is_none() {
+            return self;
+        }
+
+        let cut = match max_chars.checked_sub(1) {
+            Some(n) => self.char_indices().nth(n).map_or(0, |(idx, _)| idx),
+            None => return Cow::borrowed(""),
+        };
+
+        let mut out = if self.is_owned() {
+            let mut out = self.into_owned();
+
+            out.truncate(cut);
+            out
+        } else {
+            let mut out = String::with_capacity(cut + '…'.len_utf8());
+
+            out.push_str(&self[..cut]);
+            out
+        };
+
+        out.push('…');
+
+        Cow::owned(out)
+    }
+
+    /// Removes ANSI escape sequences, such as terminal colors and cursor movement.
+    ///
+    /// Returns the `Cow` unchanged, without allocating, if it contains no escape sequences.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let stripped = Cow::borrowed("\x1b[1;31mError:\x1b[0m oops").strip_ansi_escapes();
+    ///
+    /// assert_eq!(stripped, "Error: oops");
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn strip_ansi_escapes(self) -> Self {
+        if !self.contains(['\x1b', '\u{9b}']) {
+            return self;
+        }
+
+        let mut out = String::with_capacity(self.len());
+        let mut chars = self.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\x1b' => match chars.next() {
+                    // Control Sequence Introducer
+                    Some('[') => skip_ansi_csi(&mut chars),
+                    // Operating System Command and other string sequences,
+                    // terminated by either BEL or ST (`ESC \`)
+                    Some(']') | Some('P') | Some('X') | Some('^') | Some('_') => {
+                        while let Some(c) = chars.next() {
+                            if c == '\x07' || (c == '\x1b' && chars.next() == Some('\\')) {
+                                break;
+                            }
+                        }
+                    }
+                    // Any other escape, optionally with intermediate bytes
+                    Some(mut c) => {
+                        while (' '..='/').contains(&c) {
+                            match chars.next() {
+                                Some(next) => c = next,
+                                None => break,
+                            }
+                        }
+                    }
+                    None => (),
+                },
+                '\u{9b}' => skip_ansi_csi(&mut chars),
+                c => out.push(c),
+            }
+        }
+
+        Cow::owned(out)
+    }
+}
+
+impl<'a, U> Cow<'a, [u8], U>
+where
+    U: Capacity,
+{
+    /// Returns a reader over the bytes, implementing `Read`, `BufRead` and `Seek`,
+    /// without copying them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    /// use std::io::BufRead;
+    ///
+    /// let cow: Cow<[u8]> = Cow::owned(b"foo\nbar\n".to_vec());
+    /// let lines: Vec<String> = cow.reader().lines().map(Result::unwrap).collect();
+    ///
+    /// assert_eq!(lines, ["foo", "bar"]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn reader(&self) -> std::io::Cursor<&[u8]> {
+        std::io::Cursor::new(self.borrow())
+    }
+
+    /// Splits the bytes on the first occurrence of `byte`, returning the parts
+    /// before and after it, or `None` if `byte` is not found.
+    ///
+    /// Borrowed bytes are split into two borrowed `Cow`s. Owned bytes are split into
+    /// two owned `Cow`s, reusing the allocation for the first part.
+    ///
+    /// With the `memchr` feature enabled the search is SIMD accelerated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let (key, value) = Cow::borrowed(&b"key=value"[..]).split_once_byte(b'=').unwrap();
+    ///
+    /// assert_eq!(key, &b"key"[..]);
+    /// assert_eq!(value, &b"value"[..]);
+    /// assert!(key.is_borrowed() && value.is_borrowed());
+    /// ```
+    pub fn split_once_byte(self, byte: u8) -> Option<(Self, Self)> {
+        let idx = find_byte(byte, &self)?;
+
+        Some(match StdCow::from(self) {
+            StdCow::Borrowed(bytes) => (
+                Cow::borrowed(&bytes[..idx]),
+                Cow::borrowed(&bytes[idx + 1..]),
+            ),
+            StdCow::Owned(mut bytes) => {
+                let tail = bytes[idx + 1..].to_vec();
+
+                bytes.truncate(idx);
+
+                (Cow::owned(bytes), Cow::owned(tail))
+            }
+        })
+    }
+
+    /// Removes leading and trailing ASCII whitespace.
+    ///
+    /// Borrowed bytes are narrowed to a sub-slice, owned bytes are trimmed in place,
+    /// so this never allocates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let trimmed = Cow::borrowed(&b"\r\n Hello \t"[..]).trim_ascii();
+    ///
+    /// assert_eq!(trimmed, &b"Hello"[..]);
+    /// assert!(trimmed.is_borrowed());
+    /// ```
+    pub fn trim_ascii(self) -> Self {
+        let start = self
+            .iter()
+            .position(|b| !b.is_ascii_whitespace())
+            .unwrap_or_else(|| self.len());
+        let end = self
+            .iter()
+            .rposition(|b| !b.is_ascii_whitespace())
+            .map_or(start, |idx| idx + 1);
+
+        if start == 0 && end == self.len() {
+            return self;
+        }
+
+        match StdCow::from(self) {
+            StdCow::Borrowed(bytes) => Cow::borrowed(&bytes[start..end]),
+            StdCow::Owned(mut bytes) => {
+                bytes.truncate(end);
+                bytes.drain(..start);
+
+                Cow::owned(bytes)
+            }
+        }
+    }
+
+    /// Returns an iterator over the parts of the bytes separated by `byte`, same as
+    /// [`slice::split`](https://doc.rust-lang.org/std/primitive.slice.html#method.split).
+    ///
+    /// The iterator borrows the `Cow`, and yields `Cow`s borrowed from it, whether the
+    /// bytes are borrowed or owned, so no part is ever copied.
+    ///
+    /// With the `memchr` feature enabled the search is SIMD accelerated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    ///
+    /// let text: Cow<[u8]> = Cow::owned(b"foo\nbar\n".to_vec());
+    /// let lines: Vec<Cow<[u8]>> = text.split_on_byte(b'\n').collect();
+    ///
+    /// assert_eq!(lines, [&b"foo"[..], b"bar", b""]);
+    /// assert!(lines.iter().all(Cow::is_borrowed));
+    /// ```
+    #[inline]
+    pub fn split_on_byte(&self, byte: u8) -> SplitOnByte<'_, U> {
+        SplitOnByte {
+            bytes: self.as_ref(),
+            byte,
+            pos: 0,
+            finished: false,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Skip parameter and intermediate bytes of a control sequence, up to and
+/// including the final byte.
+#[cfg(feature = "ansi")]
+fn skip_ansi_csi(chars: &mut core::str::Chars) {
+    for c in chars {
+        if ('@'..='~').contains(&c) {
+            break;
+        }
+    }
+}
+
+#[cfg(feature = "memchr")]
+#[inline]
+fn find_byte(byte: u8, haystack: &[u8]) -> Option<usize> {
+    memchr::memchr(byte, haystack)
+}
+
+#[cfg(not(feature = "memchr"))]
+#[inline]
+fn find_byte(byte: u8, haystack: &[u8]) -> Option<usize> {
+    haystack.iter().position(|&b| b == byte)
+}
+
+/// Iterator over the parts of a `Cow<[u8]>` separated by a byte.
+///
+/// Created by [`Cow::split_on_byte`](./struct.Cow.html#method.split_on_byte).
+pub struct SplitOnByte<'a, U: Capacity> {
+    bytes: &'a [u8],
+    byte: u8,
+    pos: usize,
+    finished: bool,
+    marker: PhantomData<U>,
+}
+
+impl<'a, U> Iterator for SplitOnByte<'a, U>
+where
+    U: Capacity,
+{
+    type Item = Cow<'a, [u8], U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let start = self.pos;
+        let end = match find_byte(self.byte, &self.bytes[start..]) {
+            Some(idx) => {
+                self.pos = start + idx + 1;
+                start + idx
+            }
+            None => {
+                self.finished = true;
+                self.bytes.len()
+            }
+        };
+
+        Some(Cow::borrowed(&self.bytes[start..end]))
+    }
+}
+
+impl<U> core::iter::FusedIterator for SplitOnByte<'_, U> where U: Capacity {}
+
+/// Owning iterator over the elements of a `Cow<[T]>`.
+///
+/// Created by calling `into_iter` on a `Cow<[T]>`. Owned elements are moved out of
+/// the `Vec`, borrowed elements are cloned.
+pub struct IntoIter<'a, T: Clone> {
+    inner: IntoIterInner<'a, T>,
+}
+
+enum IntoIterInner<'a, T: Clone> {
+    Borrowed(core::slice::Iter<'a, T>),
+    Owned(alloc::vec::IntoIter<T>),
+}
+
+impl<'a, T, U> IntoIterator for Cow<'a, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    type Item = T;
+    type IntoIter = IntoIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<'a, T> {
+        let inner = match StdCow::from(self) {
+            StdCow::Borrowed(v) => IntoIterInner::Borrowed(v.iter()),
+            StdCow::Owned(v) => IntoIterInner::Owned(v.into_iter()),
+        };
+
+        IntoIter { inner }
+    }
+}
+
+impl<'b, T, U> IntoIterator for &'b Cow<'_, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    type Item = &'b T;
+    type IntoIter = core::slice::Iter<'b, T>;
+
+    #[inline]
+    fn into_iter(self) -> core::slice::Iter<'b, T> {
+        self.iter()
+    }
+}
+
+impl<T> Iterator for IntoIter<'_, T>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match self.inner {
+            IntoIterInner::Borrowed(ref mut iter) => iter.next().cloned(),
+            IntoIterInner::Owned(ref mut iter) => iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.inner {
+            IntoIterInner::Borrowed(ref iter) => iter.size_hint(),
+            IntoIterInner::Owned(ref iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<'_, T>
+where
+    T: Clone,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        match self.inner {
+            IntoIterInner::Borrowed(ref mut iter) => iter.next_back().cloned(),
+            IntoIterInner::Owned(ref mut iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<'_, T> where T: Clone {}
+
+impl<T> core::iter::FusedIterator for IntoIter<'_, T> where T: Clone {}
+
+impl<T> fmt::Debug for IntoIter<'_, T>
+where
+    T: Clone + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let remaining = match self.inner {
+            IntoIterInner::Borrowed(ref iter) => iter.as_slice(),
+            IntoIterInner::Owned(ref iter) => iter.as_slice(),
+        };
+
+        f.debug_tuple("IntoIter").field(&remaining).finish()
+    }
+}
+
+/// Mutable access to the owned data of a `Cow`.
+///
+/// Created by [`Cow::to_mut`](./struct.Cow.html#method.to_mut), the data is moved back
+/// into the `Cow` when this guard is dropped.
+pub struct OwnedMut<'c, 'a, T: Beef + ?Sized, U: Capacity> {
+    cow: &'c mut Cow<'a, T, U>,
+    owned: ManuallyDrop<T::Owned>,
+}
+
+impl<T, U> core::ops::Deref for OwnedMut<'_, '_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    type Target = T::Owned;
+
+    #[inline]
+    fn deref(&self) -> &T::Owned {
+        &self.owned
+    }
+}
+
+impl<T, U> core::ops::DerefMut for OwnedMut<'_, '_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T::Owned {
+        &mut self.owned
+    }
+}
+
+impl<T, U> Drop for OwnedMut<'_, '_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn drop(&mut self) {
+        let owned = unsafe { ManuallyDrop::take(&mut self.owned) };
+
+        *self.cow = Cow::owned(owned);
+    }
+}
+
+impl<T, U> Hash for Cow<'_, T, U>
+where
+    T: Hash + Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.borrow().hash(state)
+    }
+}
+
+impl<'a, T, U> Default for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+    &'a T: Default,
+{
+    /// Borrows an empty `str` or slice, without allocating.
+    #[inline]
+    fn default() -> Self {
+        Cow::borrowed(Default::default())
+    }
+}
+
+impl<T, U> Eq for Cow<'_, T, U>
+where
+    T: Eq + Beef + ?Sized,
+    U: Capacity,
+{
+}
+
+impl<A, B, U, V> PartialOrd<Cow<'_, B, V>> for Cow<'_, A, U>
+where
+    A: Beef + ?Sized + PartialOrd<B>,
+    B: Beef + ?Sized,
+    U: Capacity,
+    V: Capacity,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Cow<'_, B, V>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.borrow(), other.borrow())
+    }
+}
+
+impl<T, U> Ord for Cow<'_, T, U>
+where
+    T: Ord + Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(self.borrow(), other.borrow())
+    }
+}
+
+impl<'a, T, U> From<&'a T> for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn from(val: &'a T) -> Self {
+        Cow::borrowed(val)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, U> core::convert::TryFrom<&'a std::ffi::OsStr> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    /// The original `OsStr` is returned if it is not valid UTF-8.
+    type Error = &'a std::ffi::OsStr;
+
+    #[inline]
+    fn try_from(val: &'a std::ffi::OsStr) -> Result<Self, Self::Error> {
+        val.to_str().map(Cow::borrowed).ok_or(val)
+    }
+}
+
+impl<U> From<String> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(s: String) -> Self {
+        Cow::owned(s)
+    }
+}
+
+impl<T, U> From<Vec<T>> for Cow<'_, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn from(v: Vec<T>) -> Self {
+        Cow::owned(v)
+    }
+}
+
+impl<U> core::str::FromStr for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    type Err = core::convert::Infallible;
+
+    /// Copies the string into an owned `Cow`, since it can't borrow from the input.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Cow::owned(String::from(s)))
+    }
+}
+
+impl<U> From<char> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(c: char) -> Self {
+        Cow::owned(String::from(c))
+    }
+}
+
+impl<U> From<Box<str>> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    /// Takes over the boxed allocation, with capacity equal to its length.
+    #[inline]
+    fn from(s: Box<str>) -> Self {
+        Cow::owned(s.into_string())
+    }
+}
+
+impl<T, U> From<Box<[T]>> for Cow<'_, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    /// Takes over the boxed allocation, with capacity equal to its length.
+    #[inline]
+    fn from(v: Box<[T]>) -> Self {
+        Cow::owned(v.into_vec())
+    }
+}
+
+impl<'a, U> From<&'a String> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(s: &'a String) -> Self {
+        Cow::borrowed(s.as_str())
+    }
+}
+
+impl<'a, T, U> From<&'a Vec<T>> for Cow<'a, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn from(v: &'a Vec<T>) -> Self {
+        Cow::borrowed(v.as_slice())
+    }
+}
+
+impl<'a, T, U, const N: usize> From<&'a [T; N]> for Cow<'a, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn from(v: &'a [T; N]) -> Self {
+        Cow::borrowed(v)
+    }
+}
+
+impl<U> From<CString> for Cow<'_, CStr, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(s: CString) -> Self {
+        Cow::owned(s)
+    }
+}
+
+impl<'a, U> From<&'a CString> for Cow<'a, CStr, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(s: &'a CString) -> Self {
+        Cow::borrowed(s.as_c_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U> From<std::ffi::OsString> for Cow<'_, std::ffi::OsStr, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(s: std::ffi::OsString) -> Self {
+        Cow::owned(s)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, U> From<&'a std::ffi::OsString> for Cow<'a, std::ffi::OsStr, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(s: &'a std::ffi::OsString) -> Self {
+        Cow::borrowed(s.as_os_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U> From<std::path::PathBuf> for Cow<'_, std::path::Path, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(p: std::path::PathBuf) -> Self {
+        Cow::owned(p)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, U> From<&'a std::path::PathBuf> for Cow<'a, std::path::Path, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(p: &'a std::path::PathBuf) -> Self {
+        Cow::borrowed(p.as_path())
+    }
+}
+
+impl<T, U, const N: usize> From<[T; N]> for Cow<'_, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn from(v: [T; N]) -> Self {
+        Cow::owned(Vec::from(v))
+    }
+}
+
+impl<T, U> From<VecDeque<T>> for Cow<'_, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    /// Makes the deque contiguous and reuses its buffer, without reallocating.
+    #[inline]
+    fn from(v: VecDeque<T>) -> Self {
+        Cow::owned(Vec::from(v))
+    }
+}
+
+#[cfg(not(feature = "may_dangle"))]
+impl<T, U> Drop for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(capacity) = self.capacity() {
+            unsafe { T::owned_from_parts::<U>(self.ptr, self.fat, capacity) };
+        }
+    }
+}
+
+// Safety: The borrowed data is never accessed on drop, only the owned
+// data, which does not depend on the `'a` lifetime.
+#[cfg(feature = "may_dangle")]
+unsafe impl<#[may_dangle] 'a, T, U> Drop for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(capacity) = self.capacity() {
+            unsafe { T::owned_from_parts::<U>(self.ptr, self.fat, capacity) };
+        }
+    }
+}
+
+impl<'a, T, U> Clone for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        match self.capacity() {
+            Some(_) => Cow::owned(self.borrow().to_owned()),
+            None => Cow { ..*self },
+        }
+    }
+}
+
+impl<T, U> core::ops::Deref for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.borrow()
+    }
+}
+
+impl<T, U> AsRef<T> for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.borrow()
+    }
+}
+
+impl<T, U> Borrow<T> for Cow<'_, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn borrow(&self) -> &T {
         self.borrow()
     }
 }
 
-impl<'a, T, U> From<StdCow<'a, T>> for Cow<'a, T, U>
+impl<'a, T, U> From<StdCow<'a, T>> for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn from(stdcow: StdCow<'a, T>) -> Self {
+        match stdcow {
+            StdCow::Borrowed(v) => Self::borrowed(v),
+            StdCow::Owned(v) => Self::owned(v),
+        }
+    }
+}
+
+impl<'a, T, U> From<&'a StdCow<'_, T>> for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn from(stdcow: &'a StdCow<'_, T>) -> Self {
+        Self::borrowed(stdcow)
+    }
+}
+
+impl<'a, T, U> From<Cow<'a, T, U>> for StdCow<'a, T>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'a, T, U>) -> Self {
+        let cow = ManuallyDrop::new(cow);
+
+        match cow.capacity() {
+            Some(capacity) => {
+                StdCow::Owned(unsafe { T::owned_from_parts::<U>(cow.ptr, cow.fat, capacity) })
+            }
+            None => StdCow::Borrowed(unsafe { &*T::ref_from_parts::<U>(cow.ptr, cow.fat) }),
+        }
+    }
+}
+
+impl<U> From<Cow<'_, str, U>> for String
+where
+    U: Capacity,
+{
+    /// Hands back the owned buffer without copying, and only clones borrowed data.
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        cow.into_owned()
+    }
+}
+
+impl<T, U> From<Cow<'_, [T], U>> for Vec<T>
+where
+    T: Clone,
+    U: Capacity,
+{
+    /// Hands back the owned buffer without copying, and only clones borrowed data.
+    #[inline]
+    fn from(cow: Cow<'_, [T], U>) -> Self {
+        cow.into_owned()
+    }
+}
+
+impl<U> From<Cow<'_, str, U>> for Box<str>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        match StdCow::from(cow) {
+            StdCow::Borrowed(s) => Box::from(s),
+            StdCow::Owned(s) => s.into_boxed_str(),
+        }
+    }
+}
+
+impl<T, U> From<Cow<'_, [T], U>> for Box<[T]>
+where
+    T: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, [T], U>) -> Self {
+        match StdCow::from(cow) {
+            StdCow::Borrowed(v) => Box::from(v),
+            StdCow::Owned(v) => v.into_boxed_slice(),
+        }
+    }
+}
+
+impl<'a, T, U, const N: usize> core::convert::TryFrom<Cow<'a, [T], U>> for [T; N]
+where
+    T: Clone,
+    U: Capacity,
+{
+    /// The original `Cow` is returned if its length is not `N`.
+    type Error = Cow<'a, [T], U>;
+
+    /// Clones the elements out of borrowed slices, and moves them out of owned ones.
+    #[inline]
+    fn try_from(cow: Cow<'a, [T], U>) -> Result<Self, Self::Error> {
+        if cow.len() != N {
+            return Err(cow);
+        }
+
+        match StdCow::from(cow) {
+            StdCow::Borrowed(v) => Ok(core::array::from_fn(|i| v[i].clone())),
+            StdCow::Owned(v) => <[T; N]>::try_from(v).map_err(Cow::owned),
+        }
+    }
+}
+
+macro_rules! impl_from_cow_for_rc {
+    ($($rc:ident),*) => {$(
+        impl<U> From<Cow<'_, str, U>> for $rc<str>
+        where
+            U: Capacity,
+        {
+            /// Copies the data into the shared allocation exactly once.
+            #[inline]
+            fn from(cow: Cow<'_, str, U>) -> Self {
+                $rc::from(&*cow)
+            }
+        }
+
+        impl<T, U> From<Cow<'_, [T], U>> for $rc<[T]>
+        where
+            T: Clone,
+            U: Capacity,
+        {
+            /// Moves owned elements into the shared allocation, and only clones
+            /// borrowed ones.
+            #[inline]
+            fn from(cow: Cow<'_, [T], U>) -> Self {
+                match StdCow::from(cow) {
+                    StdCow::Borrowed(v) => $rc::from(v),
+                    StdCow::Owned(v) => $rc::from(v),
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_cow_for_rc!(Arc, Rc);
+
+#[cfg(feature = "std")]
+impl<'a, U> From<Cow<'_, str, U>> for Box<dyn std::error::Error + 'a>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        Box::from(String::from(cow))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, U> From<Cow<'_, str, U>> for Box<dyn std::error::Error + Send + Sync + 'a>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        Box::from(String::from(cow))
+    }
+}
+
+impl<U> FromIterator<char> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        Cow::owned(String::from_iter(iter))
+    }
+}
+
+impl<'b, U> FromIterator<&'b str> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'b str>>(iter: I) -> Self {
+        Cow::owned(String::from_iter(iter))
+    }
+}
+
+impl<U> FromIterator<String> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Cow::owned(String::from_iter(iter))
+    }
+}
+
+// A single `Cow` is returned as is, so collecting one borrowed value doesn't allocate.
+impl<'a, U> FromIterator<Cow<'a, str, U>> for Cow<'a, str, U>
 where
-    T: Beef + ?Sized,
     U: Capacity,
 {
     #[inline]
-    fn from(stdcow: StdCow<'a, T>) -> Self {
-        match stdcow {
-            StdCow::Borrowed(v) => Self::borrowed(v),
-            StdCow::Owned(v) => Self::owned(v),
-        }
+    fn from_iter<I: IntoIterator<Item = Cow<'a, str, U>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let first = match iter.next() {
+            Some(cow) => cow,
+            None => return Cow::borrowed(""),
+        };
+        let second = match iter.next() {
+            Some(cow) => cow,
+            None => return first,
+        };
+
+        let mut buf = first.into_owned();
+        buf.push_str(&second);
+        buf.extend(iter);
+        Cow::owned(buf)
     }
 }
 
-impl<'a, T, U> From<Cow<'a, T, U>> for StdCow<'a, T>
+impl<T, U> FromIterator<T> for Cow<'_, [T], U>
 where
-    T: Beef + ?Sized,
+    T: Clone,
     U: Capacity,
 {
+    /// Collects into an owned `Vec`. An empty iterator doesn't allocate, and produces
+    /// an empty, borrowed `Cow`.
     #[inline]
-    fn from(cow: Cow<'a, T, U>) -> Self {
-        let cow = ManuallyDrop::new(cow);
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Cow::owned(Vec::from_iter(iter))
+    }
+}
 
-        match cow.capacity() {
-            Some(capacity) => {
-                StdCow::Owned(unsafe { T::owned_from_parts::<U>(cow.ptr, cow.fat, capacity) })
+// Same as `std`, the buffer of the first `Cow` is reused if it is owned.
+impl<'a, U> FromIterator<Cow<'a, str, U>> for String
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Cow<'a, str, U>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut buf = match iter.next() {
+            Some(cow) => cow.into_owned(),
+            None => String::new(),
+        };
+        buf.extend(iter);
+        buf
+    }
+}
+
+impl<'a, T, U> FromIterator<Cow<'a, [T], U>> for Vec<T>
+where
+    T: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Cow<'a, [T], U>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut buf = match iter.next() {
+            Some(cow) => cow.into_owned(),
+            None => Vec::new(),
+        };
+        buf.extend(iter);
+        buf
+    }
+}
+
+impl<'a, U> Extend<Cow<'a, str, U>> for String
+where
+    U: Capacity,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = Cow<'a, str, U>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(move |s| self.push_str(&s));
+    }
+}
+
+impl<'a, T, U> Extend<Cow<'a, [T], U>> for Vec<T>
+where
+    T: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = Cow<'a, [T], U>>>(&mut self, iter: I) {
+        iter.into_iter()
+            .for_each(move |v| self.extend_from_slice(&v));
+    }
+}
+
+// Borrowed data is only copied out once the iterator yields an item.
+macro_rules! impl_extend {
+    ($($(@for< $t:ident: $bound:ident >)? $ptr:ty => <$item:ty>,)*) => {$(
+        impl<'b, U $(, $t)*> Extend<$item> for Cow<'_, $ptr, U>
+        where
+            U: Capacity,
+            $( $t: $bound, )*
+        {
+            #[inline]
+            fn extend<I: IntoIterator<Item = $item>>(&mut self, iter: I) {
+                let mut iter = iter.into_iter().peekable();
+
+                if iter.peek().is_some() {
+                    self.to_mut().extend(iter);
+                }
             }
-            None => StdCow::Borrowed(unsafe { &*T::ref_from_parts::<U>(cow.ptr, cow.fat) }),
         }
+    )*};
+}
+
+impl_extend! {
+    str => <char>,
+    str => <&'b char>,
+    str => <&'b str>,
+    @for<T: Clone> [T] => <T>,
+    @for<T: Copy> [T] => <&'b T>,
+}
+
+impl<U> fmt::Write for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    /// Borrowed data is copied out on the first non-empty write.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !s.is_empty() {
+            self.push_str(s);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U> std::io::Write for Cow<'_, [u8], U>
+where
+    U: Capacity,
+{
+    /// Borrowed data is copied out on the first non-empty write.
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if !buf.is_empty() {
+            self.to_mut().extend_from_slice(buf);
+        }
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a, U> core::ops::Add<&'a str> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: &'a str) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<'a, U> core::ops::Add<Cow<'a, str, U>> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Cow<'a, str, U>) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<U> core::ops::Add<Cow<'_, str, U>> for String
+where
+    U: Capacity,
+{
+    type Output = String;
+
+    #[inline]
+    fn add(mut self, rhs: Cow<'_, str, U>) -> String {
+        self.push_str(&rhs);
+        self
+    }
+}
+
+impl<U> core::ops::Add<&Cow<'_, str, U>> for String
+where
+    U: Capacity,
+{
+    type Output = String;
+
+    #[inline]
+    fn add(mut self, rhs: &Cow<'_, str, U>) -> String {
+        self.push_str(rhs);
+        self
+    }
+}
+
+impl<U> core::ops::AddAssign<Cow<'_, str, U>> for String
+where
+    U: Capacity,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Cow<'_, str, U>) {
+        self.push_str(&rhs);
+    }
+}
+
+impl<U> core::ops::AddAssign<&Cow<'_, str, U>> for String
+where
+    U: Capacity,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &Cow<'_, str, U>) {
+        self.push_str(rhs);
+    }
+}
+
+impl<'a, U> core::ops::AddAssign<&'a str> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a str) {
+        // Keep the spare capacity of an empty, owned buffer
+        if self.is_empty() && self.is_borrowed() {
+            *self = Cow::borrowed(rhs);
+        } else if !rhs.is_empty() {
+            self.push_str(rhs);
+        }
+    }
+}
+
+impl<'a, U> core::ops::AddAssign<Cow<'a, str, U>> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Cow<'a, str, U>) {
+        if self.is_empty() && self.is_borrowed() {
+            *self = rhs;
+        } else if !rhs.is_empty() {
+            self.push_str(&rhs);
+        }
+    }
+}
+
+/// Moves the owned buffer over without copying.
+#[cfg(target_pointer_width = "64")]
+impl<'a, T> From<Cow<'a, T, Lean>> for Cow<'a, T, Wide>
+where
+    T: Beef + ?Sized,
+{
+    #[inline]
+    fn from(cow: Cow<'a, T, Lean>) -> Self {
+        Cow::from(StdCow::from(cow))
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl<'a, T> core::convert::TryFrom<Cow<'a, T, Wide>> for Cow<'a, T, Lean>
+where
+    T: Beef + ?Sized,
+{
+    /// The original `Cow` is returned if its length or capacity doesn't fit in 32 bits.
+    type Error = Cow<'a, T, Wide>;
+
+    /// Moves the owned buffer over without copying.
+    #[inline]
+    fn try_from(cow: Cow<'a, T, Wide>) -> Result<Self, Self::Error> {
+        let capacity = cow.cap.map_or(0, |cap| cap.get());
+
+        if cow.fat > u32::MAX as usize || capacity > u32::MAX as usize {
+            return Err(cow);
+        }
+
+        Ok(Cow::from(StdCow::from(cow)))
+    }
+}
+
+#[cfg(feature = "pattern")]
+impl<'b, U> core::str::pattern::Pattern for &'b Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    type Searcher<'a> = <&'b str as core::str::pattern::Pattern>::Searcher<'a>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &str) -> Self::Searcher<'_> {
+        self.borrow().into_searcher(haystack)
+    }
+
+    #[inline]
+    fn is_contained_in(self, haystack: &str) -> bool {
+        self.borrow().is_contained_in(haystack)
+    }
+
+    #[inline]
+    fn is_prefix_of(self, haystack: &str) -> bool {
+        self.borrow().is_prefix_of(haystack)
+    }
+
+    #[inline]
+    fn strip_prefix_of(self, haystack: &str) -> Option<&str> {
+        self.borrow().strip_prefix_of(haystack)
+    }
+
+    #[inline]
+    fn is_suffix_of<'a>(self, haystack: &'a str) -> bool
+    where
+        Self::Searcher<'a>: core::str::pattern::ReverseSearcher<'a>,
+    {
+        self.borrow().is_suffix_of(haystack)
+    }
+
+    #[inline]
+    fn strip_suffix_of<'a>(self, haystack: &'a str) -> Option<&'a str>
+    where
+        Self::Searcher<'a>: core::str::pattern::ReverseSearcher<'a>,
+    {
+        self.borrow().strip_suffix_of(haystack)
+    }
+
+    #[inline]
+    fn as_utf8_pattern(&self) -> Option<core::str::pattern::Utf8Pattern<'_>> {
+        Some(core::str::pattern::Utf8Pattern::StringPattern(self))
     }
 }
 
@@ -509,6 +2226,54 @@ where
     }
 }
 
+impl<A, B, U> PartialEq<StdCow<'_, B>> for Cow<'_, A, U>
+where
+    A: Beef + ?Sized + PartialEq<B>,
+    B: ToOwned + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn eq(&self, other: &StdCow<B>) -> bool {
+        PartialEq::eq(self.borrow(), &**other)
+    }
+}
+
+impl<A, B, U> PartialEq<Cow<'_, B, U>> for StdCow<'_, A>
+where
+    A: ToOwned + ?Sized + PartialEq<B>,
+    B: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn eq(&self, other: &Cow<B, U>) -> bool {
+        PartialEq::eq(&**self, other.borrow())
+    }
+}
+
+impl<A, B, U> PartialOrd<StdCow<'_, B>> for Cow<'_, A, U>
+where
+    A: Beef + ?Sized + PartialOrd<B>,
+    B: ToOwned + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &StdCow<B>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(self.borrow(), &**other)
+    }
+}
+
+impl<A, B, U> PartialOrd<Cow<'_, B, U>> for StdCow<'_, A>
+where
+    A: ToOwned + ?Sized + PartialOrd<B>,
+    B: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Cow<B, U>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, other.borrow())
+    }
+}
+
 macro_rules! impl_eq {
     ($($(@for< $bounds:tt >)? $ptr:ty => $([$($deref:tt)+])? <$with:ty>,)*) => {$(
         impl<U $(, $bounds)*> PartialEq<$with> for Cow<'_, $ptr, U>
@@ -541,6 +2306,81 @@ impl_eq! {
     str => <String>,
     @for<T> [T] => <[T]>,
     @for<T> [T] => [*]<&[T]>,
+    @for<T> [T] => [&**]<&mut [T]>,
+    @for<T> [T] => [&**]<Vec<T>>,
+    CStr => <CStr>,
+    CStr => [*]<&CStr>,
+    CStr => [&**]<CString>,
+}
+
+#[cfg(feature = "std")]
+impl_eq! {
+    std::ffi::OsStr => <std::ffi::OsStr>,
+    std::ffi::OsStr => [*]<&std::ffi::OsStr>,
+    std::ffi::OsStr => [&**]<std::ffi::OsString>,
+    std::ffi::OsStr => <str>,
+    std::path::Path => <std::path::Path>,
+    std::path::Path => [*]<&std::path::Path>,
+    std::path::Path => [&**]<std::path::PathBuf>,
+    str => <std::ffi::OsStr>,
+    str => <std::ffi::OsString>,
+}
+
+// There is no `PartialEq<str>` for `Path`, so the string is compared as a path.
+#[cfg(feature = "std")]
+impl<U> PartialEq<std::path::Path> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn eq(&self, other: &std::path::Path) -> bool {
+        std::path::Path::new(self.borrow()) == other
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U> PartialEq<Cow<'_, str, U>> for std::path::Path
+where
+    U: Capacity,
+{
+    #[inline]
+    fn eq(&self, other: &Cow<str, U>) -> bool {
+        self == std::path::Path::new(other.borrow())
+    }
+}
+
+macro_rules! impl_ord {
+    ($($(@for< $bounds:tt >)? $ptr:ty => $([$($deref:tt)+])? <$with:ty>,)*) => {$(
+        impl<U $(, $bounds)*> PartialOrd<$with> for Cow<'_, $ptr, U>
+        where
+            U: Capacity,
+            $( $bounds: Clone + PartialOrd, )*
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &$with) -> Option<Ordering> {
+                PartialOrd::partial_cmp(self.borrow(), $($($deref)*)* other)
+            }
+        }
+
+        impl<U $(, $bounds)*> PartialOrd<Cow<'_, $ptr, U>> for $with
+        where
+            U: Capacity,
+            $( $bounds: Clone + PartialOrd, )*
+        {
+            #[inline]
+            fn partial_cmp(&self, other: &Cow<$ptr, U>) -> Option<Ordering> {
+                PartialOrd::partial_cmp($($($deref)*)* self, other.borrow())
+            }
+        }
+    )*};
+}
+
+impl_ord! {
+    str => <str>,
+    str => [*]<&str>,
+    str => [&**]<String>,
+    @for<T> [T] => <[T]>,
+    @for<T> [T] => [*]<&[T]>,
     @for<T> [T] => [&**]<Vec<T>>,
 }
 
@@ -566,7 +2406,9 @@ where
     }
 }
 
-// Safety: Same bounds as `std::borrow::Cow`.
+// Safety: Same bounds as `std::borrow::Cow`. A shared `&Cow` only ever hands out
+// a `&T`, which is backed either by borrowed data or by the owned buffer.
+/// A `Cow` is `Sync` when both the borrowed and owned forms are.
 unsafe impl<T, U> Sync for Cow<'_, T, U>
 where
     U: Capacity,
@@ -575,6 +2417,10 @@ where
 {
 }
 
+// Safety: Same bounds as `std::borrow::Cow`. Sending a `Cow` either sends a `&T`,
+// which requires `T: Sync`, or moves the owned buffer to the other thread.
+/// A `Cow` is `Send` when its borrowed form can be shared and its owned form sent
+/// across threads, so a non-`Send` element type doesn't make the `Cow` `Send`.
 unsafe impl<T, U> Send for Cow<'_, T, U>
 where
     U: Capacity,
@@ -0,0 +1,83 @@
+use alloc::borrow::Cow as StdCow;
+use alloc::string::String;
+
+use glib::{GStr, GString};
+
+use crate::generic::{Capacity, Cow};
+
+// Owned buffers are moved into the `GString`, which only needs to append the
+// trailing nul, borrowed strings are copied.
+impl<U> From<Cow<'_, str, U>> for GString
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        GString::from(StdCow::from(cow))
+    }
+}
+
+// Strings allocated by Rust are moved out of the `GString`, strings allocated
+// by glib itself have to be copied.
+impl<U> From<GString> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(gstring: GString) -> Self {
+        Cow::owned(String::from(gstring))
+    }
+}
+
+impl<'a, U> From<&'a GString> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(gstring: &'a GString) -> Self {
+        Cow::borrowed(gstring.as_str())
+    }
+}
+
+impl<'a, U> From<&'a GStr> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(gstr: &'a GStr) -> Self {
+        Cow::borrowed(gstr.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glib::{gstr, GString};
+
+    #[test]
+    fn wide_cow_into_gstring() {
+        use crate::Cow;
+
+        let gstring = GString::from(Cow::<str>::owned("Hello".to_owned()));
+
+        assert_eq!(gstring, "Hello");
+
+        let cow: Cow<str> = Cow::from(&gstring);
+
+        assert_eq!(cow, "Hello");
+        assert!(cow.is_borrowed());
+    }
+
+    #[test]
+    fn lean_cow_from_gstring() {
+        use crate::lean::Cow;
+
+        let cow: Cow<str> = Cow::from(GString::from("World"));
+
+        assert_eq!(cow, "World");
+        assert!(cow.is_owned());
+
+        let cow: Cow<str> = Cow::from(gstr!("static"));
+
+        assert!(cow.is_borrowed());
+    }
+}
@@ -0,0 +1,150 @@
+//! Helpers for converting between `Cow`s and [JNI](https://docs.rs/jni) strings and byte arrays.
+//!
+//! Java strings are stored in modified UTF-8. [`with_str`](./fn.with_str.html) borrows
+//! their contents for the duration of a closure whenever they are also valid UTF-8,
+//! and only allocates when re-encoding is necessary.
+
+use alloc::borrow::Cow as StdCow;
+
+use jni::errors::Result;
+use jni::objects::{JByteArray, JString};
+use jni::Env;
+
+use crate::generic::{Capacity, Cow};
+
+/// Calls `f` with the contents of a Java string.
+///
+/// The `Cow` borrows the string data held by the JVM, which is released once `f`
+/// returns. It is owned only if the modified UTF-8 had to be re-encoded.
+#[inline]
+pub fn with_str<R, U, F>(env: &Env<'_>, string: &JString<'_>, f: F) -> Result<R>
+where
+    U: Capacity,
+    F: FnOnce(Cow<'_, str, U>) -> R,
+{
+    let chars = string.mutf8_chars(env)?;
+
+    Ok(f(Cow::from(StdCow::<str>::from(&chars))))
+}
+
+/// Copies the contents of a Java string into an owned `Cow`.
+#[inline]
+pub fn to_str<U>(env: &Env<'_>, string: &JString<'_>) -> Result<Cow<'static, str, U>>
+where
+    U: Capacity,
+{
+    with_str(env, string, |cow: Cow<str, U>| Cow::owned(cow.into_owned()))
+}
+
+/// Creates a new Java string from a `Cow`.
+#[inline]
+pub fn new_string<'local, U>(
+    env: &mut Env<'local>,
+    cow: &Cow<'_, str, U>,
+) -> Result<JString<'local>>
+where
+    U: Capacity,
+{
+    env.new_string(cow)
+}
+
+/// Copies the contents of a Java byte array into an owned `Cow`.
+#[inline]
+pub fn to_bytes<U>(env: &Env<'_>, array: &JByteArray<'_>) -> Result<Cow<'static, [u8], U>>
+where
+    U: Capacity,
+{
+    env.convert_byte_array(array).map(Cow::owned)
+}
+
+/// Creates a new Java byte array from a `Cow`.
+#[inline]
+pub fn new_byte_array<'local, U>(
+    env: &mut Env<'local>,
+    cow: &Cow<'_, [u8], U>,
+) -> Result<JByteArray<'local>>
+where
+    U: Capacity,
+{
+    env.byte_array_from_slice(cow)
+}
+
+#[cfg(all(test, feature = "jni_invocation"))]
+mod tests {
+    use super::{new_byte_array, new_string, to_bytes, to_str, with_str};
+    use jni::{InitArgsBuilder, JavaVM};
+    use std::sync::OnceLock;
+
+    // Only one JVM can ever be started per process, so all tests share it
+    fn jvm() -> &'static JavaVM {
+        static JVM: OnceLock<JavaVM> = OnceLock::new();
+
+        JVM.get_or_init(|| {
+            let args = InitArgsBuilder::new()
+                .option("-Xcheck:jni")
+                .build()
+                .unwrap();
+
+            JavaVM::new(args).unwrap()
+        })
+    }
+
+    #[test]
+    fn wide_cow_str_roundtrip() {
+        use crate::Cow;
+
+        jvm()
+            .attach_current_thread(|env| -> jni::errors::Result<()> {
+                let string = new_string(env, &Cow::borrowed("Hello beef"))?;
+                let len = with_str(env, &string, |cow: Cow<str>| {
+                    assert!(cow.is_borrowed());
+                    cow.len()
+                })?;
+                let owned: Cow<str> = to_str(env, &string)?;
+
+                assert_eq!(len, 10);
+                assert!(owned.is_owned());
+                assert_eq!(owned, "Hello beef");
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn wide_cow_str_reencoded() {
+        use crate::Cow;
+
+        jvm()
+            .attach_current_thread(|env| -> jni::errors::Result<()> {
+                // Modified UTF-8 encodes both NUL and characters outside the BMP differently
+                let string = new_string(env, &Cow::borrowed("\0🥩"))?;
+
+                with_str(env, &string, |cow: Cow<str>| {
+                    assert!(cow.is_owned());
+                    assert_eq!(cow, "\0🥩");
+                })?;
+
+                let owned: Cow<str> = to_str(env, &string)?;
+
+                assert_eq!(owned, "\0🥩");
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn lean_cow_bytes_roundtrip() {
+        use crate::lean::Cow;
+
+        jvm()
+            .attach_current_thread(|env| -> jni::errors::Result<()> {
+                let array = new_byte_array(env, &Cow::borrowed(&b"\xFFbeef"[..]))?;
+                let bytes: Cow<[u8]> = to_bytes(env, &array)?;
+
+                assert!(bytes.is_owned());
+                assert_eq!(bytes, &b"\xFFbeef"[..]);
+                Ok(())
+            })
+            .unwrap();
+    }
+}
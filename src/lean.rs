@@ -6,12 +6,36 @@ use crate::traits::InternalCapacity;
 /// and it puts both capacity and length together in a fat pointer. Both length and capacity
 /// is limited to 32 bits.
 ///
+/// It has the same methods as the top level `beef::Cow`, but `cow_vec!`, `cow_concat!`
+/// and `CowMap` only produce or store the top level `beef::Cow`, so code using those
+/// needs more than a changed import to switch.
+///
 /// # Panics
 ///
-/// [`Cow::owned`](../generic/struct.Cow.html#method.owned) will panic if capacity is larger than `u32::max_size()`. Use the
-/// top level `beef::Cow` if you wish to avoid this problem.
+/// [`Cow::owned`](../generic/struct.Cow.html#method.owned) will panic if capacity is larger than `u32::MAX`,
+/// and both it and [`Cow::borrowed`](../generic/struct.Cow.html#method.borrowed) will panic if length is.
+/// Use the top level `beef::Cow` if you wish to avoid this problem.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::lean::Cow;
+/// use std::mem::size_of;
+///
+/// let borrowed: Cow<str> = Cow::borrowed("Hello");
+/// let owned: Cow<str> = Cow::owned(String::from("World"));
+///
+/// assert_eq!(format!("{} {}!", borrowed, owned), "Hello World!");
+/// assert_eq!(size_of::<Cow<str>>(), 2 * size_of::<usize>());
+/// ```
 pub type Cow<'a, T> = crate::generic::Cow<'a, T, Lean>;
 
+/// Shorthand for a two word `Cow<str>`.
+pub type Str<'a> = Cow<'a, str>;
+
+/// Shorthand for a two word `Cow<[u8]>`.
+pub type Bytes<'a> = Cow<'a, [u8]>;
+
 pub(crate) mod internal {
     #[derive(Clone, Copy, PartialEq, Eq)]
     pub struct Lean;
@@ -26,6 +50,15 @@ impl Lean {
     pub const fn mask_len(len: usize) -> usize {
         len & MASK_LO
     }
+
+    #[inline]
+    pub const fn check_len(len: usize) -> usize {
+        if len & MASK_HI != 0 {
+            panic!("beef::lean::Cow: Length out of bounds");
+        }
+
+        len
+    }
 }
 
 impl InternalCapacity for Lean {
@@ -39,7 +72,7 @@ impl InternalCapacity for Lean {
 
     #[inline]
     fn empty(len: usize) -> (usize, Lean) {
-        (len & MASK_LO, Lean)
+        (Lean::check_len(len), Lean)
     }
 
     #[inline]
@@ -48,7 +81,7 @@ impl InternalCapacity for Lean {
             panic!("beef::lean::Cow: Capacity out of bounds");
         }
 
-        let fat = ((capacity & MASK_LO) << 32) | (len & MASK_LO);
+        let fat = ((capacity & MASK_LO) << 32) | Lean::check_len(len);
 
         (fat, Lean)
     }
@@ -67,3 +100,18 @@ impl InternalCapacity for Lean {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Cow;
+    use alloc::vec;
+
+    #[test]
+    #[should_panic(expected = "Length out of bounds")]
+    fn borrowed_length_out_of_bounds() {
+        // Zero-sized elements don't allocate, however many there are
+        let long = vec![(); u32::MAX as usize + 1];
+
+        let _ = Cow::borrowed(&long[..]);
+    }
+}
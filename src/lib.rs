@@ -21,30 +21,141 @@
 //! + `beef::Cow` is 3 words wide: pointer, length, and capacity. It stores the ownership tag in capacity.
 //! + `beef::lean::Cow` is 2 words wide, storing length, capacity, and the ownership tag all in one word.
 //!
-//! Both versions are leaner than the `std::borrow::Cow`:
+//! Both versions are at least as lean as the `std::borrow::Cow`:
 //!
 //! ```rust
 //! use std::mem::size_of;
 //!
 //! const WORD: usize = size_of::<usize>();
 //!
-//! assert_eq!(size_of::<std::borrow::Cow<str>>(), 4 * WORD);
+//! assert!(size_of::<std::borrow::Cow<str>>() >= 3 * WORD);
 //! assert_eq!(size_of::<beef::Cow<str>>(), 3 * WORD);
 //!
 //! // Lean variant is two words on 64-bit architecture
 //! #[cfg(target_pointer_width = "64")]
 //! assert_eq!(size_of::<beef::lean::Cow<str>>(), 2 * WORD);
+//!
+//! // Pointer is never null, so wrapping in `Option` is free
+//! assert_eq!(size_of::<Option<beef::Cow<str>>>(), 3 * WORD);
 //! ```
-#![cfg_attr(feature = "const_fn", feature(const_fn_trait_bound))]
+//!
+//! ## `no_std`
+//!
+//! The crate is `no_std` by default and only depends on `alloc`. Impls for types that
+//! only exist in `std`, such as `Cow<OsStr>` and `Box<dyn Error>`, as well as the
+//! [`collections`](./collections/index.html) module, are enabled by the `std` feature.
+#![cfg_attr(feature = "may_dangle", feature(dropck_eyepatch))]
+#![cfg_attr(feature = "pattern", feature(pattern))]
+#![cfg_attr(feature = "ptr_metadata", feature(ptr_metadata))]
 #![warn(missing_docs)]
 #![cfg_attr(not(test), no_std)]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[macro_use]
+mod macros;
+mod owned;
+#[cfg(test)]
+mod parity;
 mod traits;
 mod wide;
 
-#[cfg(feature = "impl_serde")]
-mod serde;
+#[cfg(feature = "ptr_metadata")]
+mod dst;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "redis")]
+mod redis;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "diesel")]
+mod diesel;
+
+#[cfg(feature = "postgres")]
+mod postgres;
+
+#[cfg(feature = "axum")]
+mod axum;
+
+#[cfg(feature = "actix-web")]
+mod actix;
+
+#[cfg(feature = "kv")]
+mod log;
+
+#[cfg(feature = "minijinja")]
+mod minijinja;
+
+#[cfg(feature = "mlua")]
+mod mlua;
+
+#[cfg(feature = "uniffi")]
+mod uniffi;
+
+#[cfg(feature = "glib")]
+mod glib;
+
+#[cfg(feature = "bytes")]
+mod bytes;
+
+#[cfg(feature = "smallvec")]
+mod smallvec;
+
+#[cfg(feature = "compact_str")]
+mod compact_str;
+
+#[cfg(feature = "smol_str")]
+mod smol_str;
+
+#[cfg(feature = "smartstring")]
+mod smartstring;
+
+#[cfg(feature = "equivalent")]
+mod equivalent;
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+mod pool;
+
+#[cfg(feature = "schemars")]
+mod schemars;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "bson")]
+pub mod bson;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "jni")]
+pub mod jni;
+
+#[cfg(feature = "thin-vec")]
+pub mod thin;
+
+#[cfg(all(feature = "windows", windows))]
+pub mod windows;
+
+#[cfg(feature = "std")]
+pub mod collections;
 
 pub mod generic;
 #[cfg(target_pointer_width = "64")]
@@ -53,10 +164,102 @@ pub mod lean;
 #[cfg(not(target_pointer_width = "64"))]
 pub mod lean {
     /// Re-exports 3-word Cow for non-64-bit targets
-    pub use super::wide::Cow;
+    pub use super::wide::{Bytes, Cow, Str};
 }
 
-pub use wide::Cow;
+pub use owned::Owned;
+pub use traits::{IntoCow, Steak, Transparent};
+
+#[cfg(feature = "ptr_metadata")]
+pub use dst::Dst;
+
+/// Derives [`Transparent`](./trait.Transparent.html), and with it `Beef`, for a
+/// `#[repr(transparent)]` newtype around `str`, `[T]` or another `Beef` type.
+///
+/// The owned type of the newtype must implement `From` and `Into` the owned type of
+/// the wrapped one. Add `#[beef(empty_is_valid)]` if an empty field, such as `""`, is a
+/// valid value of the newtype, so that `Cow::to_mut` doesn't clone owned data.
+///
+/// ```rust
+/// use beef::Cow;
+/// use std::borrow::Borrow;
+///
+/// #[derive(beef::Beef, Debug, PartialEq)]
+/// #[repr(transparent)]
+/// struct Ident(str);
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct IdentBuf(String);
+///
+/// impl Borrow<Ident> for IdentBuf {
+///     fn borrow(&self) -> &Ident {
+///         Ident::new(&self.0)
+///     }
+/// }
+///
+/// impl ToOwned for Ident {
+///     type Owned = IdentBuf;
+///
+///     fn to_owned(&self) -> IdentBuf {
+///         IdentBuf(self.0.to_owned())
+///     }
+/// }
+///
+/// impl Ident {
+///     fn new(ident: &str) -> &Ident {
+///         unsafe { &*(ident as *const str as *const Ident) }
+///     }
+/// }
+///
+/// impl From<String> for IdentBuf {
+///     fn from(s: String) -> Self {
+///         IdentBuf(s)
+///     }
+/// }
+///
+/// impl From<IdentBuf> for String {
+///     fn from(ident: IdentBuf) -> Self {
+///         ident.0
+///     }
+/// }
+///
+/// let borrowed: Cow<Ident> = Cow::borrowed(Ident::new("foo"));
+/// let owned: Cow<Ident> = Cow::owned(IdentBuf("bar".to_string()));
+///
+/// assert_eq!(&*borrowed, Ident::new("foo"));
+/// assert!(borrowed.is_borrowed());
+/// assert_eq!(owned.into_owned(), IdentBuf("bar".to_string()));
+/// ```
+#[cfg(feature = "derive")]
+pub use beef_derive::Beef;
+pub use wide::{Bytes, Cow, Str};
+
+/// Convenience re-exports of the 3-word `Cow`, its aliases, `Owned`, and the `IntoCow` trait.
+///
+/// ```rust
+/// use beef::prelude::*;
+///
+/// fn greet(name: Str) -> Str<'static> {
+///     Cow::owned(format!("Hello {}!", name))
+/// }
+///
+/// let bytes: Bytes = Cow::borrowed(b"beef");
+///
+/// assert_eq!(greet(Cow::borrowed("World")), "Hello World!");
+/// assert_eq!(bytes.len(), 4);
+/// ```
+pub mod prelude {
+    pub use crate::owned::Owned;
+    pub use crate::traits::IntoCow;
+    pub use crate::wide::{Bytes, Cow, Str};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::borrow::ToOwned;
+    pub use alloc::string::String;
+    pub use alloc::vec;
+}
 
 #[rustfmt::skip]
 macro_rules! test { ($tmod:ident => $cow:path) => {
@@ -92,6 +295,899 @@ macro_rules! test { ($tmod:ident => $cow:path) => {
             assert_eq!(owned.into_owned(), hello);
         }
 
+        #[test]
+        fn from_display() {
+            let cow: Cow<str> = Cow::from_display(format_args!("{}-{}", "foo", 42));
+
+            assert_eq!(cow, "foo-42");
+            assert!(cow.is_owned());
+
+            let empty: Cow<str> = Cow::from_display("");
+
+            assert!(empty.is_empty());
+        }
+
+        #[test]
+        fn to_mut() {
+            let mut owned: Cow<str> = Cow::owned(String::with_capacity(16));
+            let ptr = owned.as_ptr();
+
+            owned.to_mut().push_str("Hello");
+
+            assert_eq!(owned, "Hello");
+            assert_eq!(owned.as_ptr(), ptr);
+
+            let mut borrowed: Cow<[u8]> = Cow::borrowed(&[1, 2]);
+
+            // Leaking the guard leaves the borrow as it was. Its buffer is taken out
+            // first, so the test itself doesn't leak memory under Miri.
+            let mut guard = borrowed.to_mut();
+            let taken = std::mem::take(&mut *guard);
+
+            std::mem::forget(guard);
+
+            assert_eq!(taken, [1, 2]);
+            assert_eq!(borrowed, &[1, 2][..]);
+            assert!(borrowed.is_borrowed());
+        }
+
+        #[test]
+        fn to_mut_leaked_guard() {
+            let mut owned: Cow<str> = Cow::owned(String::from("Hello"));
+
+            // Owned data is moved out, leaving an empty borrow behind
+            let mut guard = owned.to_mut();
+            let taken = std::mem::take(&mut *guard);
+
+            std::mem::forget(guard);
+
+            assert_eq!(taken, "Hello");
+            assert_eq!(owned, "");
+            assert!(owned.is_borrowed());
+        }
+
+        #[test]
+        fn to_mut_leaked_guard_cstr() {
+            use alloc::ffi::CString;
+            use core::ffi::CStr;
+
+            let source = CStr::from_bytes_with_nul(b"beef\0").unwrap();
+            let mut borrowed: Cow<CStr> = Cow::borrowed(source);
+            let mut guard = borrowed.to_mut();
+
+            // Drop the copy in place, so the test itself doesn't leak memory under Miri
+            unsafe { core::ptr::drop_in_place::<CString>(&mut *guard) };
+            std::mem::forget(guard);
+
+            assert_eq!(borrowed.to_bytes(), b"beef");
+            assert!(borrowed.is_borrowed());
+        }
+
+        #[test]
+        fn to_mut_leaked_guard_owned_cstr() {
+            use alloc::ffi::CString;
+
+            let mut owned: Cow<core::ffi::CStr> = Cow::owned(CString::new("beef").unwrap());
+            let mut guard = owned.to_mut();
+            let taken = core::mem::take(&mut *guard);
+
+            // Owned data is moved out, leaving an empty, but still NUL terminated, borrow
+            unsafe { core::ptr::drop_in_place::<CString>(&mut *guard) };
+            std::mem::forget(guard);
+
+            assert_eq!(taken.as_bytes(), b"beef");
+            assert_eq!(owned.to_bytes(), b"");
+            assert_eq!(owned.to_bytes_with_nul(), b"\0");
+            assert!(owned.is_borrowed());
+        }
+
+        #[test]
+        fn collect_into_std() {
+            let owned: Cow<str> = Cow::owned(String::with_capacity(16));
+            let ptr = owned.as_ptr();
+            let string: String = vec![owned, Cow::borrowed("foo"), Cow::borrowed("bar")]
+                .into_iter()
+                .collect();
+
+            assert_eq!(string, "foobar");
+            assert_eq!(string.as_ptr(), ptr.as_ptr());
+
+            let mut vec: Vec<u8> = vec![Cow::borrowed(&[1, 2][..]), Cow::owned(vec![3])]
+                .into_iter()
+                .collect();
+
+            vec.extend(vec![Cow::borrowed(&[4][..])]);
+
+            assert_eq!(vec, [1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn add_str() {
+            let empty: Cow<str> = Cow::borrowed("");
+            let borrowed = empty + "Hello";
+
+            assert!(borrowed.is_borrowed());
+
+            let mut owned = borrowed + " World";
+
+            owned += Cow::borrowed("!");
+
+            assert_eq!(owned, "Hello World!");
+            assert!(owned.is_owned());
+        }
+
+        #[test]
+        fn add_reuses_capacity() {
+            let mut owned: Cow<str> = Cow::owned(String::with_capacity(32));
+
+            owned += "Hello";
+
+            let ptr = owned.as_ptr();
+            let owned = owned + " World" + Cow::borrowed("!");
+
+            assert_eq!(owned, "Hello World!");
+            assert_eq!(owned.as_ptr(), ptr);
+
+            let mut borrowed: Cow<str> = Cow::borrowed("Hello");
+
+            borrowed += " World";
+
+            // Borrowed data is copied out once, with the exact space needed
+            assert_eq!(borrowed.into_owned().capacity(), 11);
+        }
+
+        #[test]
+        fn string_add_cow() {
+            let world: Cow<str> = Cow::owned(String::from(" World"));
+            let mut string = String::from("Hello") + &world + Cow::borrowed("!");
+
+            string += &world;
+            string += Cow::borrowed("!");
+
+            assert_eq!(string, "Hello World! World!");
+        }
+
+        #[test]
+        fn update_or_keep() {
+            let owned: Cow<[u8]> = Cow::owned(vec![1, 2, 3]);
+            let ptr = owned.as_ptr();
+            let kept = owned.update_or_keep(|_| None);
+
+            assert_eq!(kept.as_ptr(), ptr);
+
+            let updated = kept.update_or_keep(|v| Some(v.iter().rev().cloned().collect()));
+
+            assert_eq!(updated, [3, 2, 1][..]);
+
+            let borrowed = Cow::borrowed("foo").update_or_keep(|_| None);
+
+            assert!(borrowed.is_borrowed());
+        }
+
+        #[test]
+        fn split_once() {
+            let owned: Cow<str> = Cow::owned(String::from("a, b, c"));
+            let ptr = owned.as_ptr();
+            let (head, tail) = owned.split_once(", ").unwrap();
+
+            assert_eq!(head, "a");
+            assert_eq!(tail, "b, c");
+            assert_eq!(head.as_ptr(), ptr);
+            assert!(tail.is_owned());
+
+            let (head, tail) = Cow::borrowed("a, b, c").rsplit_once(&[',', ' '][..]).unwrap();
+
+            assert_eq!(head, "a, b,");
+            assert_eq!(tail, "c");
+            assert!(head.is_borrowed() && tail.is_borrowed());
+
+            let (head, tail) = Cow::borrowed("añb").split_once(|c: char| !c.is_ascii()).unwrap();
+
+            assert_eq!((head, tail), (Cow::borrowed("a"), Cow::borrowed("b")));
+            assert!(Cow::borrowed("abc").rsplit_once(['x', 'y']).is_none());
+        }
+
+        #[test]
+        fn split_first_last() {
+            let owned: Cow<[String]> = Cow::owned(vec!["a".into(), "b".into(), "c".into()]);
+            let (first, rest) = owned.split_first().unwrap();
+
+            assert_eq!(first, "a");
+            assert!(rest.is_borrowed());
+            assert!(std::ptr::eq(&rest[0], &owned[1]));
+
+            let (last, rest) = rest.split_last().unwrap();
+
+            assert_eq!(last, "c");
+            assert_eq!(rest, ["b".to_owned()][..]);
+            assert!(rest.is_borrowed());
+            assert!(std::ptr::eq(&rest[0], &owned[1]));
+            assert!(owned.is_owned());
+
+            let borrowed: Cow<[u8]> = Cow::borrowed(&[1]);
+            let (last, rest) = borrowed.split_last().unwrap();
+
+            assert_eq!(*last, 1);
+            assert!(rest.is_empty() && rest.is_borrowed());
+            assert!(rest.split_first().is_none());
+            assert!(rest.split_last().is_none());
+        }
+
+        #[test]
+        fn filter_chars() {
+            let owned: Cow<str> = Cow::owned(String::from("a-b-c"));
+            let ptr = owned.as_ptr();
+            let filtered = owned.filter_chars(|c| c != '-');
+
+            assert_eq!(filtered, "abc");
+            assert_eq!(filtered.as_ptr(), ptr);
+
+            let filtered = Cow::borrowed("añb").filter_chars(|c| c.is_ascii());
+
+            assert_eq!(filtered, "ab");
+            assert!(filtered.is_owned());
+            assert!(Cow::borrowed("abc").filter_chars(|c| c.is_ascii()).is_borrowed());
+        }
+
+        #[test]
+        fn filter_chars_stateful() {
+            // Rejects only the 2nd character it is called with
+            fn second() -> impl FnMut(char) -> bool {
+                let mut calls = 0;
+
+                move |_| {
+                    calls += 1;
+                    calls != 2
+                }
+            }
+
+            let mut calls = 0;
+            let filtered = Cow::borrowed("abcdef").filter_chars(|_| {
+                calls += 1;
+                true
+            });
+
+            assert_eq!(filtered, "abcdef");
+            assert_eq!(calls, 6);
+
+            let owned: Cow<str> = Cow::owned(String::from("abcdef"));
+
+            assert_eq!(Cow::borrowed("abcdef").filter_chars(second()), "acdef");
+            assert_eq!(owned.filter_chars(second()), "acdef");
+        }
+
+        #[test]
+        fn try_into_array() {
+            use std::convert::TryFrom;
+
+            let borrowed: Cow<[u8]> = Cow::borrowed(b"GET ");
+            let owned: Cow<[String]> = Cow::owned(vec!["a".into(), "b".into()]);
+
+            assert_eq!(<[u8; 4]>::try_from(borrowed), Ok(*b"GET "));
+            assert_eq!(<[String; 2]>::try_from(owned).unwrap(), ["a", "b"]);
+
+            let short: Cow<[u8]> = Cow::owned(vec![1, 2]);
+            let err = <[u8; 3]>::try_from(short).unwrap_err();
+
+            assert_eq!(err, [1, 2][..]);
+            assert!(err.is_owned());
+        }
+
+        #[test]
+        fn convert_flavors() {
+            use std::convert::TryFrom;
+
+            let owned: Cow<str> = Cow::owned(String::from("Hello"));
+            let ptr = owned.as_ptr();
+            let wide = crate::Cow::from(crate::lean::Cow::try_from(owned).unwrap());
+            let lean = crate::lean::Cow::try_from(wide).unwrap();
+
+            assert_eq!(lean, "Hello");
+            assert_eq!(lean.as_ptr(), ptr);
+            assert!(lean.is_owned());
+
+            let borrowed = crate::lean::Cow::try_from(crate::Cow::borrowed("World")).unwrap();
+
+            assert!(borrowed.is_borrowed());
+        }
+
+        #[test]
+        fn is_borrowed_from() {
+            let buffer = vec![1u8, 2, 3, 4];
+            let tail: Cow<[u8]> = Cow::borrowed(&buffer[2..]);
+            let empty: Cow<[u8]> = Cow::borrowed(&buffer[4..]);
+
+            assert!(tail.is_borrowed_from(&buffer));
+            assert!(empty.is_borrowed_from(&buffer));
+            assert!(!tail.is_borrowed_from(&buffer[..3]));
+            assert!(!Cow::<[u8]>::owned(buffer.clone()).is_borrowed_from(&buffer));
+        }
+
+        #[test]
+        fn cstr() {
+            use std::ffi::{CStr, CString};
+
+            let c = CString::new("Hello").unwrap();
+            let ptr = c.as_ptr();
+            let borrowed: Cow<CStr> = Cow::from(&c);
+            let owned: Cow<CStr> = Cow::from(c.clone());
+
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+            assert_eq!(borrowed, owned);
+            assert_eq!((*borrowed).as_ptr(), ptr);
+            assert_eq!(owned.to_bytes_with_nul(), b"Hello\0");
+            assert_eq!(owned, c);
+        }
+
+        #[test]
+        fn cstr_into_owned_keeps_nul() {
+            use std::ffi::{CStr, CString};
+
+            let owned: Cow<CStr> = Cow::owned(CString::new("World").unwrap());
+            let ptr = (*owned).as_ptr();
+            let c = owned.into_owned();
+
+            assert_eq!(c.as_ptr(), ptr);
+            assert_eq!(c.as_bytes_with_nul(), b"World\0");
+
+            let empty: Cow<CStr> = Cow::owned(CString::default());
+
+            assert!(empty.is_owned());
+            assert_eq!(empty.into_owned().as_bytes_with_nul(), b"\0");
+
+            let hi = CStr::from_bytes_with_nul(b"Hi\0").unwrap();
+
+            assert_eq!(Cow::borrowed(hi).into_owned().as_bytes_with_nul(), b"Hi\0");
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn os_str() {
+            use std::ffi::{OsStr, OsString};
+
+            let os = OsString::from("Hello");
+            let borrowed: Cow<OsStr> = Cow::from(&os);
+            let owned: Cow<OsStr> = Cow::from(os.clone());
+            let ptr = owned.as_encoded_bytes().as_ptr();
+
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+            assert_eq!(borrowed, owned);
+            assert_eq!(owned, os);
+            assert_eq!(owned, *"Hello");
+
+            let bytes = owned.into_owned().into_encoded_bytes();
+
+            assert_eq!(bytes.as_ptr(), ptr);
+            assert!(Cow::<OsStr>::owned(OsString::new()).is_borrowed());
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn path() {
+            use std::path::{Path, PathBuf};
+
+            let path = PathBuf::from("/usr/lib");
+            let borrowed: Cow<Path> = Cow::from(&path);
+            let owned: Cow<Path> = Cow::from(path.clone());
+
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+            assert_eq!(borrowed, owned);
+            assert_eq!(owned, path);
+            assert_eq!(owned.join("beef"), Path::new("/usr/lib/beef"));
+
+            let buf: PathBuf = owned.into_owned();
+
+            assert_eq!(buf, path);
+        }
+
+        #[cfg(feature = "pattern")]
+        #[test]
+        fn pattern() {
+            let needle: Cow<str> = Cow::owned(String::from(", "));
+            let haystack = "foo, bar, baz";
+
+            assert_eq!(haystack.find(&needle), Some(3));
+            assert_eq!(haystack.split(&needle).collect::<Vec<_>>(), ["foo", "bar", "baz"]);
+            assert_eq!(haystack.replace(&needle, "-"), "foo-bar-baz");
+            assert_eq!(haystack.strip_suffix(&needle), None);
+        }
+
+        #[test]
+        fn ownership_state() {
+            let borrowed: Cow<str> = Cow::borrowed("Hello");
+            let mut owned = borrowed.clone();
+
+            assert!(borrowed.is_borrowed() && !borrowed.is_owned());
+            assert!(owned.is_borrowed());
+            assert!(Cow::<[u8]>::default().is_borrowed());
+
+            owned.to_mut().push('!');
+
+            assert!(owned.is_owned() && !owned.is_borrowed());
+            assert!(owned.clone().is_owned());
+            assert!(borrowed.is_borrowed());
+        }
+
+        #[test]
+        fn to_mut_reuses_owned() {
+            let mut cow: Cow<[u8]> = Cow::owned(Vec::with_capacity(16));
+
+            cow.to_mut().extend_from_slice(b"foo");
+
+            let ptr = cow.as_ptr();
+
+            {
+                let vec: &mut Vec<u8> = &mut cow.to_mut();
+
+                vec.push(b'!');
+            }
+
+            assert_eq!(cow, &b"foo!"[..]);
+            assert_eq!(cow.as_ptr(), ptr);
+        }
+
+        #[test]
+        fn try_unwrap_borrowed() {
+            let text = String::from("Hello");
+            let unwrapped: &str = {
+                let cow: Cow<str> = Cow::borrowed(&text);
+
+                cow.try_unwrap_borrowed().unwrap()
+            };
+
+            assert_eq!(unwrapped.as_ptr(), text.as_ptr());
+
+            let owned: Cow<[u8]> = Cow::owned(vec![1, 2, 3]);
+            let ptr = owned.as_ptr();
+            let err = owned.try_unwrap_borrowed().unwrap_err();
+
+            assert!(err.is_owned());
+            assert_eq!(err.as_ptr(), ptr);
+        }
+
+        #[test]
+        fn option_niche() {
+            use std::mem::size_of;
+
+            assert_eq!(size_of::<Option<Cow<str>>>(), size_of::<Cow<str>>());
+            assert_eq!(size_of::<Option<Cow<[u64]>>>(), size_of::<Cow<[u64]>>());
+
+            let none: Option<Cow<str>> = None;
+            let some: Option<Cow<str>> = Some(Cow::borrowed(""));
+
+            assert!(none.is_none());
+            assert_eq!(some.unwrap(), "");
+        }
+
+        #[test]
+        fn send_sync() {
+            fn send<T: Send>(_: T) {}
+            fn sync<T: Sync>(_: T) {}
+
+            send(Cow::<str>::borrowed("foo"));
+            sync(Cow::<str>::borrowed("foo"));
+            send(Cow::<[String]>::owned(vec![String::new()]));
+            sync(Cow::<[String]>::owned(vec![String::new()]));
+
+            let owned: Cow<str> = Cow::owned(String::from("Hello"));
+            let joined = std::thread::spawn(move || owned).join().unwrap();
+
+            assert_eq!(joined, "Hello");
+        }
+
+        #[test]
+        fn into_string_and_vec() {
+            fn takes_string(s: impl Into<String>) -> String {
+                s.into()
+            }
+
+            let owned: Cow<str> = Cow::owned(String::from("Hello"));
+            let ptr = owned.as_ptr();
+            let string = takes_string(owned);
+
+            assert_eq!(string, "Hello");
+            assert_eq!(string.as_ptr(), ptr.as_ptr());
+            assert_eq!(takes_string(Cow::borrowed("World")), "World");
+
+            let owned: Cow<[u8]> = Cow::owned(vec![1, 2, 3]);
+            let ptr = owned.as_ptr();
+            let vec = Vec::from(owned);
+
+            assert_eq!(vec, [1, 2, 3]);
+            assert_eq!(vec.as_ptr(), ptr.as_ptr());
+        }
+
+        #[test]
+        fn from_box() {
+            let boxed: Box<str> = "Hello".into();
+            let ptr = boxed.as_ptr();
+            let cow: Cow<str> = Cow::from(boxed);
+
+            assert_eq!(cow, "Hello");
+            assert!(cow.is_owned());
+            assert_eq!(cow.as_ptr().as_ptr() as *const u8, ptr);
+
+            let boxed: Box<[u16]> = vec![1, 2, 3].into_boxed_slice();
+            let ptr = boxed.as_ptr();
+            let cow: Cow<[u16]> = boxed.into();
+
+            assert_eq!(cow, [1, 2, 3][..]);
+            assert_eq!(cow.as_ptr().as_ptr() as *const u16, ptr);
+            assert!(Cow::<str>::from(Box::<str>::default()).is_borrowed());
+        }
+
+        #[test]
+        fn into_boxed() {
+            let mut string = String::with_capacity(32);
+            string.push_str("Hello");
+
+            let boxed = Cow::<str>::owned(string).into_boxed_str();
+
+            assert_eq!(&*boxed, "Hello");
+
+            let boxed = Cow::<[u8]>::borrowed(&[1, 2]).into_boxed_slice();
+
+            assert_eq!(&*boxed, [1, 2]);
+            assert!(Cow::<[u8]>::default().into_boxed_slice().is_empty());
+        }
+
+        #[test]
+        fn into_shared_moves_elements() {
+            use std::rc::Rc;
+            use std::sync::Arc;
+
+            let item = Rc::new(1);
+            let owned: Cow<[Rc<u8>]> = Cow::owned(vec![item.clone(), item.clone()]);
+            let shared: Rc<[Rc<u8>]> = owned.into();
+
+            assert_eq!(Rc::strong_count(&item), 3);
+            drop(shared);
+
+            let borrowed: Cow<[Rc<u8>]> = Cow::borrowed(std::slice::from_ref(&item));
+            let shared: Arc<[Rc<u8>]> = borrowed.into();
+
+            assert_eq!(Rc::strong_count(&item), 2);
+            assert_eq!(&*Arc::<str>::from(Cow::<str>::borrowed("Hello")), "Hello");
+            drop(shared);
+        }
+
+        #[test]
+        fn collect_cows() {
+            let empty: Cow<str> = Vec::<Cow<str>>::new().into_iter().collect();
+            let single: Cow<str> = vec![Cow::borrowed("foo")].into_iter().collect();
+            let many: Cow<str> = vec![
+                Cow::borrowed("foo"),
+                Cow::owned(String::from("bar")),
+                Cow::borrowed("baz"),
+            ]
+            .into_iter()
+            .collect();
+
+            assert_eq!(empty, "");
+            assert_eq!(single, "foo");
+            assert_eq!(many, "foobarbaz");
+            assert!(single.is_borrowed());
+            assert!(many.is_owned());
+        }
+
+        #[test]
+        fn collect_slice() {
+            let squares: Cow<[u32]> = (1..=4).map(|n| n * n).collect();
+            let strings: Cow<[String]> = "a b".split(' ').map(String::from).collect();
+            let empty: Cow<[u8]> = std::iter::empty().collect();
+
+            assert_eq!(squares, [1, 4, 9, 16][..]);
+            assert!(squares.is_owned());
+            assert_eq!(strings, ["a".to_owned(), "b".to_owned()][..]);
+            assert!(empty.is_empty());
+            assert!(empty.is_borrowed());
+        }
+
+        #[test]
+        fn extend() {
+            let mut text: Cow<str> = Cow::borrowed("Hello");
+
+            text.extend(std::iter::empty::<char>());
+
+            assert!(text.is_borrowed());
+
+            text.extend([',', ' ']);
+            text.extend(vec!["World", "!"]);
+
+            assert_eq!(text, "Hello, World!");
+            assert!(text.is_owned());
+
+            let mut bytes: Cow<[u8]> = Cow::owned(Vec::with_capacity(8));
+            let ptr = bytes.as_ptr();
+
+            bytes.extend(vec![1, 2]);
+            bytes.extend(&[3, 4]);
+
+            assert_eq!(bytes, [1, 2, 3, 4][..]);
+            assert_eq!(bytes.as_ptr(), ptr);
+
+            let mut strings: Cow<[String]> = Cow::borrowed(&[]);
+
+            strings.extend(Some(String::from("foo")));
+
+            assert_eq!(strings, [String::from("foo")][..]);
+        }
+
+        #[test]
+        fn fmt_write() {
+            use std::fmt::Write;
+
+            let mut cow: Cow<str> = Cow::borrowed("Hello");
+
+            write!(cow, "").unwrap();
+
+            assert!(cow.is_borrowed());
+
+            write!(cow, ", {}{}", "World", '!').unwrap();
+
+            assert_eq!(cow, "Hello, World!");
+            assert!(cow.is_owned());
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn io_write() {
+            use std::io::Write;
+
+            let mut cow: Cow<[u8]> = Cow::borrowed(b"GET");
+
+            cow.write_all(b"").unwrap();
+
+            assert!(cow.is_borrowed());
+
+            write!(cow, " /{} HTTP/1.1", "index.html").unwrap();
+            cow.flush().unwrap();
+
+            assert_eq!(cow, &b"GET /index.html HTTP/1.1"[..]);
+            assert!(cow.is_owned());
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn reader() {
+            use std::io::{Read, Seek, SeekFrom};
+
+            let cow: Cow<[u8]> = Cow::borrowed(b"Hello World");
+            let mut reader = cow.reader();
+            let mut buf = [0; 5];
+
+            reader.seek(SeekFrom::Start(6)).unwrap();
+            reader.read_exact(&mut buf).unwrap();
+
+            assert_eq!(&buf, b"World");
+            assert_eq!(reader.into_inner().as_ptr(), cow.as_ptr().as_ptr());
+        }
+
+        #[test]
+        fn into_iter() {
+            let owned: Cow<[String]> = Cow::owned(vec![String::from("foo"), String::from("bar")]);
+            let ptr = owned[0].as_ptr();
+            let mut iter = owned.into_iter();
+
+            assert_eq!(iter.len(), 2);
+            assert_eq!(format!("{:?}", iter), "IntoIter([\"foo\", \"bar\"])");
+
+            let foo = iter.next().unwrap();
+
+            // Moved out of the `Vec`, not cloned
+            assert_eq!(foo.as_ptr(), ptr);
+            assert_eq!(iter.next_back().as_deref(), Some("bar"));
+            assert_eq!(iter.next(), None);
+
+            let borrowed: Cow<[u8]> = Cow::borrowed(&[1, 2, 3]);
+            let mut sum = 0;
+
+            for n in borrowed {
+                sum += n;
+            }
+
+            assert_eq!(sum, 6);
+        }
+
+        #[test]
+        fn iterate_by_ref() {
+            fn total<'a>(items: impl IntoIterator<Item = &'a u32>) -> u32 {
+                items.into_iter().sum()
+            }
+
+            let cow: Cow<[u32]> = Cow::owned(vec![1, 2, 3]);
+
+            assert_eq!(total(&cow), 6);
+
+            for n in &cow {
+                assert!(*n > 0);
+            }
+
+            // `chars` and `bytes` come from `str`, through `Deref`
+            let text: Cow<str> = Cow::borrowed("abc");
+
+            assert_eq!(text.chars().rev().collect::<String>(), "cba");
+            assert_eq!(text.bytes().len(), 3);
+        }
+
+        #[test]
+        fn index_ranges() {
+            // Indexing auto-derefs to `str` and `[T]`, so no `Index` impls are needed
+            let text: Cow<str> = Cow::borrowed("Hello World");
+            let bytes: Cow<[u8]> = Cow::owned(b"Hello".to_vec());
+
+            assert_eq!(&text[6..], "World");
+            assert_eq!(&text[..5], "Hello");
+            assert_eq!(&text[1..4], "ell");
+            assert_eq!(&text[..=1], "He");
+            assert_eq!(&text[..], "Hello World");
+            assert_eq!(bytes[0], b'H');
+            assert_eq!(&bytes[1..=2], b"el");
+        }
+
+        #[test]
+        fn checked_get() {
+            let text: Cow<str> = Cow::owned(String::from("Grüße"));
+            let bytes: Cow<[u8]> = Cow::borrowed(b"abc");
+
+            assert_eq!(text.get(..2), Some("Gr"));
+            assert_eq!(text.get(..3), None);
+            assert_eq!(text.get(2..10), None);
+            assert_eq!(bytes.get(1..), Some(&b"bc"[..]));
+            assert_eq!(bytes.get(3), None);
+        }
+
+        #[test]
+        fn from_utf8() {
+            let bytes = b"Hello".to_vec();
+            let ptr = bytes.as_ptr();
+            let owned = Cow::<str>::from_utf8(Cow::owned(bytes)).unwrap();
+
+            assert_eq!(owned, "Hello");
+            assert_eq!(owned.as_ptr().as_ptr() as *const u8, ptr);
+
+            let err = Cow::<str>::from_utf8(Cow::owned(b"ab\xFF".to_vec())).unwrap_err();
+
+            assert_eq!(err.valid_up_to(), 2);
+
+            let lossy = Cow::<str>::from_utf8_lossy(b"\xFFab");
+
+            assert_eq!(lossy, "\u{FFFD}ab");
+            assert!(lossy.is_owned());
+        }
+
+        #[test]
+        fn from_utf16() {
+            let utf16: Vec<u16> = "Hello ☃".encode_utf16().collect();
+            let cow: Cow<'static, str> = Cow::from_utf16(&utf16).unwrap();
+
+            assert_eq!(cow, "Hello ☃");
+            assert!(cow.is_owned());
+            assert!(Cow::<str>::from_utf16(&[]).unwrap().is_borrowed());
+            assert!(Cow::<str>::from_utf16(&[0xDC00]).is_err());
+            assert_eq!(Cow::<str>::from_utf16_lossy(&[0x61, 0xDC00]), "a\u{FFFD}");
+        }
+
+        #[test]
+        fn from_char() {
+            let cow: Cow<'static, str> = Cow::from('☃');
+            let parts: Vec<Cow<str>> = vec!['a'.into(), "bc".into()];
+
+            assert_eq!(cow, "☃");
+            assert!(cow.is_owned());
+            assert_eq!(parts.concat(), "abc");
+        }
+
+        #[test]
+        fn from_array() {
+            const ARRAY: [u8; 3] = [1, 2, 3];
+
+            let borrowed: Cow<[u8]> = Cow::from(&ARRAY);
+            let owned: Cow<[u8]> = Cow::from([1, 2, 3]);
+            let empty: Cow<[String]> = Cow::from([]);
+
+            assert_eq!(borrowed, owned);
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+            assert!(empty.is_borrowed());
+        }
+
+        #[test]
+        fn from_str() {
+            fn parse_all<T: std::str::FromStr>(input: &str) -> Vec<T> {
+                input.split(',').filter_map(|part| part.parse().ok()).collect()
+            }
+
+            let cow: Cow<'static, str> = "hello".parse().unwrap();
+
+            assert_eq!(cow, "hello");
+            assert!(cow.is_owned());
+            assert_eq!(parse_all::<Cow<str>>("a,b"), ["a", "b"]);
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn compare_os_str_and_path() {
+            use std::ffi::{OsStr, OsString};
+            use std::path::Path;
+
+            let cow: Cow<str> = Cow::borrowed("src/lib.rs");
+
+            assert_eq!(cow, *OsStr::new("src/lib.rs"));
+            assert_eq!(*OsStr::new("src/lib.rs"), cow);
+            assert_eq!(cow, OsString::from("src/lib.rs"));
+            assert_eq!(OsString::from("src/lib.rs"), cow);
+            assert_eq!(cow, *Path::new("src/lib.rs"));
+            assert_eq!(*Path::new("src/lib.rs"), cow);
+            assert_ne!(cow, *Path::new("src/main.rs"));
+        }
+
+        #[test]
+        fn collapse_whitespace() {
+            let collapsed = Cow::borrowed(" Hello \t\n World  ").collapse_whitespace();
+
+            assert_eq!(collapsed, " Hello World ");
+            assert!(collapsed.is_owned());
+
+            let newline = Cow::borrowed("Hello\nWorld").collapse_whitespace();
+
+            assert_eq!(newline, "Hello World");
+
+            let unchanged = Cow::borrowed(" Hello World ").collapse_whitespace();
+
+            assert_eq!(unchanged, " Hello World ");
+            assert!(unchanged.is_borrowed());
+        }
+
+        #[test]
+        fn truncate_with_ellipsis() {
+            let truncated = Cow::borrowed("Zażółć gęślą jaźń").truncate_with_ellipsis(6);
+
+            assert_eq!(truncated, "Zażół…");
+            assert!(truncated.is_owned());
+
+            let owned: Cow<str> = Cow::owned(String::from("Hello World"));
+            let ptr = owned.as_ptr();
+            let truncated = owned.truncate_with_ellipsis(1);
+
+            assert_eq!(truncated, "…");
+            assert_eq!(truncated.as_ptr(), ptr);
+
+            let empty = Cow::borrowed("Hello").truncate_with_ellipsis(0);
+
+            assert_eq!(empty, "");
+
+            let unchanged = Cow::borrowed("Hello").truncate_with_ellipsis(5);
+
+            assert_eq!(unchanged, "Hello");
+            assert!(unchanged.is_borrowed());
+        }
+
+        #[test]
+        #[cfg(feature = "ansi")]
+        fn strip_ansi_escapes() {
+            let colors = Cow::borrowed("\x1b[1;31mError\x1b[0m: \u{9b}4mfoo\u{9b}m").strip_ansi_escapes();
+
+            assert_eq!(colors, "Error: foo");
+            assert!(colors.is_owned());
+
+            let title = Cow::borrowed("\x1b]0;title\x07foo\x1b]8;;link\x1b\\bar").strip_ansi_escapes();
+
+            assert_eq!(title, "foobar");
+
+            let charset = Cow::borrowed("\x1b(Bfoo\x1b=bar\x1b").strip_ansi_escapes();
+
+            assert_eq!(charset, "foobar");
+
+            let unchanged = Cow::borrowed("Zażółć [0m").strip_ansi_escapes();
+
+            assert_eq!(unchanged, "Zażółć [0m");
+            assert!(unchanged.is_borrowed());
+        }
+
         #[test]
         fn borrowed_slice() {
             let s: &[_] = &[1, 2, 42];
@@ -110,6 +1206,88 @@ macro_rules! test { ($tmod:ident => $cow:path) => {
             assert_eq!(s, c);
         }
 
+        #[test]
+        fn from_vec_deque() {
+            use std::collections::VecDeque;
+
+            let mut deque = VecDeque::with_capacity(4);
+
+            deque.push_back(2);
+            deque.push_back(42);
+            deque.push_front(1);
+
+            let ptr = deque.as_slices().1.as_ptr();
+            let c: Cow<[_]> = Cow::from(deque);
+
+            assert_eq!(c, &[1, 2, 42][..]);
+            assert!(c.is_owned());
+            assert_eq!(c.as_ptr().as_ptr() as *const i32, ptr);
+        }
+
+        #[test]
+        fn split_once_byte() {
+            let (head, tail) = Cow::borrowed(&b"foo:bar:baz"[..]).split_once_byte(b':').unwrap();
+
+            assert_eq!(head, &b"foo"[..]);
+            assert_eq!(tail, &b"bar:baz"[..]);
+            assert!(head.is_borrowed() && tail.is_borrowed());
+
+            let owned: Cow<[u8]> = Cow::owned(b"foo:".to_vec());
+            let ptr = owned.as_ptr();
+            let (head, tail) = owned.split_once_byte(b':').unwrap();
+
+            assert_eq!(head, &b"foo"[..]);
+            assert_eq!(head.as_ptr(), ptr);
+            assert!(tail.is_empty());
+
+            assert!(Cow::borrowed(&b"foo"[..]).split_once_byte(b':').is_none());
+        }
+
+        #[test]
+        fn split_on_byte() {
+            let empty: Cow<[u8]> = Cow::borrowed(&b""[..]);
+            let parts: Vec<Cow<[u8]>> = empty.split_on_byte(b',').collect();
+
+            assert_eq!(parts, [&b""[..]]);
+
+            let borrowed: Cow<[u8]> = Cow::borrowed(&b",foo,,bar"[..]);
+            let parts: Vec<Cow<[u8]>> = borrowed.split_on_byte(b',').collect();
+
+            assert_eq!(parts, [&b""[..], b"foo", b"", b"bar"]);
+            assert!(parts.iter().all(Cow::is_borrowed));
+
+            let owned: Cow<[u8]> = Cow::owned(b"foo,bar,".to_vec());
+            let parts: Vec<Cow<[u8]>> = owned.split_on_byte(b',').collect();
+
+            assert_eq!(parts, [&b"foo"[..], b"bar", b""]);
+            assert!(parts.iter().all(Cow::is_borrowed));
+            assert_eq!(parts[0].as_ptr(), owned.as_ptr());
+        }
+
+        #[test]
+        fn trim_ascii() {
+            let trimmed = Cow::borrowed(&b" \tfoo bar\n"[..]).trim_ascii();
+
+            assert_eq!(trimmed, &b"foo bar"[..]);
+            assert!(trimmed.is_borrowed());
+
+            let owned: Cow<[u8]> = Cow::owned(b"  foo  ".to_vec());
+            let ptr = owned.as_ptr();
+            let trimmed = owned.trim_ascii();
+
+            assert_eq!(trimmed, &b"foo"[..]);
+            assert_eq!(trimmed.as_ptr(), ptr);
+
+            let blank = Cow::borrowed(&b" \r\n "[..]).trim_ascii();
+
+            assert!(blank.is_empty());
+
+            let unchanged: Cow<[u8]> = Cow::owned(b"foo".to_vec());
+            let ptr = unchanged.as_ptr();
+
+            assert_eq!(unchanged.trim_ascii().as_ptr(), ptr);
+        }
+
         #[test]
         fn into_owned_vec() {
             let hello: &[u8] = b"Hello World";
@@ -198,6 +1376,56 @@ macro_rules! test { ($tmod:ident => $cow:path) => {
             generate_order_tests!(cmp => Ordering::Greater => "b", "a");
         }
 
+        #[test]
+        fn partial_ord_mixed() {
+            let cow = Cow::borrowed("b");
+            let string = String::from("b");
+
+            assert!(cow > "a");
+            assert!(cow < *"c");
+            assert!(cow <= string);
+            assert!("a" < cow);
+            assert!(*"c" > cow);
+            assert!(string >= cow);
+
+            let cow: Cow<[u8]> = Cow::owned(vec![1, 2]);
+            let slice: &[u8] = &[1, 3];
+
+            assert!(cow < slice);
+            assert!(cow < *slice);
+            assert!(cow > vec![1]);
+            assert!(slice > cow);
+            assert!(*slice > cow);
+            assert!(vec![1] < cow);
+        }
+
+        #[test]
+        fn into_cow() {
+            use crate::IntoCow;
+
+            fn take<'a>(val: impl IntoCow<'a, str>) -> Cow<'a, str> {
+                val.into_cow()
+            }
+
+            let string = String::from("Hello");
+
+            assert!(take("Hello").is_borrowed());
+            assert!(take(&string).is_borrowed());
+            assert!(take(std::borrow::Cow::Borrowed("Hello")).is_borrowed());
+            assert!(take(crate::Cow::borrowed("Hello")).is_borrowed());
+            assert!(take(crate::lean::Cow::owned(string.clone())).is_owned());
+            assert!(take(string).is_owned());
+
+            let vec = vec![1, 2, 3];
+            let borrowed: Cow<[i32]> = (&vec).into_cow();
+
+            assert!(borrowed.is_borrowed());
+
+            let owned: Cow<[i32]> = vec.clone().into_cow();
+
+            assert!(owned.is_owned());
+        }
+
         #[test]
         fn from_std_cow() {
             let std = std::borrow::Cow::Borrowed("Hello World");
@@ -206,6 +1434,41 @@ macro_rules! test { ($tmod:ident => $cow:path) => {
             assert_eq!(&*std, &*beef);
         }
 
+        #[test]
+        fn from_std_cow_ref() {
+            let std: std::borrow::Cow<str> = std::borrow::Cow::Owned("Hello World".to_owned());
+            let beef = Cow::from(&std);
+
+            assert_eq!(&*std, &*beef);
+            assert!(beef.is_borrowed());
+        }
+
+        #[test]
+        #[cfg(feature = "std")]
+        fn try_from_os_str() {
+            use std::convert::TryFrom;
+            use std::ffi::OsStr;
+
+            let os = OsStr::new("Hello World");
+            let c = Cow::try_from(os).unwrap();
+
+            assert_eq!(c, "Hello World");
+            assert!(c.is_borrowed());
+        }
+
+        #[test]
+        #[cfg(unix)]
+        #[cfg(feature = "std")]
+        fn try_from_os_str_invalid() {
+            use std::convert::TryFrom;
+            use std::ffi::OsStr;
+            use std::os::unix::ffi::OsStrExt;
+
+            let os = OsStr::from_bytes(b"Hello \xFF");
+
+            assert_eq!(Cow::<str>::try_from(os).unwrap_err(), os);
+        }
+
         #[test]
         fn unwrap_borrowed() {
             let borrowed = Cow::borrowed("Hello");
@@ -221,6 +1484,18 @@ macro_rules! test { ($tmod:ident => $cow:path) => {
             borrowed.unwrap_borrowed();
         }
 
+        #[test]
+        #[cfg(feature = "may_dangle")]
+        fn may_dangle() {
+            let mut cows = Vec::new();
+            let hello = String::from("Hello");
+
+            cows.push(Cow::borrowed(hello.as_str()));
+            cows.push(Cow::owned(String::from("World")));
+
+            assert_eq!(cows, ["Hello", "World"]);
+        }
+
         #[test]
         fn stress_test_owned() {
             let mut expected = String::from("Hello... ");
@@ -258,13 +1533,28 @@ macro_rules! test { ($tmod:ident => $cow:path) => {
         }
 
         #[test]
-        #[cfg(feature = "const_fn")]
         fn const_fn_slice() {
             const FOO: Cow<[u8]> = Cow::const_slice(b"bar");
 
             assert_eq!(&*FOO, b"bar");
         }
 
+        #[test]
+        fn const_fn_static_table() {
+            static NAMES: [Cow<'static, str>; 3] = [
+                Cow::const_str("foo"),
+                Cow::const_str("bar"),
+                Cow::const_str("baz"),
+            ];
+            static SLICES: [Cow<'static, [u16]>; 2] =
+                [Cow::const_slice(&[1, 2]), Cow::const_slice(&[])];
+
+            assert_eq!(NAMES.join(","), "foo,bar,baz");
+            assert!(NAMES.iter().all(Cow::is_borrowed));
+            assert_eq!(SLICES[0], [1, 2][..]);
+            assert!(SLICES[1].is_empty());
+        }
+
         #[test]
         fn default_str() {
             let empty: Cow<str> = Default::default();
@@ -278,6 +1568,80 @@ macro_rules! test { ($tmod:ident => $cow:path) => {
 
             assert_eq!(&*empty, b"");
         }
+
+        #[test]
+        fn derive_default() {
+            #[derive(Default)]
+            struct Node<'a> {
+                name: Cow<'a, str>,
+                children: Cow<'a, [u32]>,
+            }
+
+            let node = Node::default();
+
+            assert_eq!(node.name, "");
+            assert!(node.children.is_empty());
+            assert!(node.name.is_borrowed());
+            assert!(node.children.is_borrowed());
+        }
+
+        #[test]
+        fn steak_newtype() {
+            use crate::Steak;
+            use alloc::borrow::Borrow;
+            use ref_cast::RefCast;
+
+            #[derive(RefCast, Debug, PartialEq)]
+            #[repr(transparent)]
+            struct Ident(str);
+
+            #[derive(Clone, Debug, PartialEq)]
+            struct IdentBuf(String);
+
+            impl Borrow<Ident> for IdentBuf {
+                fn borrow(&self) -> &Ident {
+                    Ident::ref_cast(&self.0)
+                }
+            }
+
+            impl ToOwned for Ident {
+                type Owned = IdentBuf;
+
+                fn to_owned(&self) -> IdentBuf {
+                    IdentBuf(self.0.to_owned())
+                }
+            }
+
+            impl Steak for Ident {
+                type Inner = str;
+
+                fn as_inner(&self) -> &str {
+                    &self.0
+                }
+
+                fn from_inner_ref(inner: &str) -> &Ident {
+                    Ident::ref_cast(inner)
+                }
+
+                fn into_inner_owned(owned: IdentBuf) -> String {
+                    owned.0
+                }
+
+                fn from_inner_owned(owned: String) -> IdentBuf {
+                    IdentBuf(owned)
+                }
+            }
+
+            let borrowed: Cow<Ident> = Cow::borrowed(Ident::ref_cast("foo"));
+            let owned: Cow<Ident> = Cow::owned(IdentBuf("bar".to_string()));
+
+            assert!(borrowed.is_borrowed());
+            assert!(owned.is_owned());
+            assert_eq!(&*borrowed, Ident::ref_cast("foo"));
+            assert_eq!(&*owned, Ident::ref_cast("bar"));
+            assert_eq!(borrowed.into_owned(), IdentBuf("foo".to_string()));
+            assert_eq!(owned.into_owned(), IdentBuf("bar".to_string()));
+        }
     }
 } }
 
@@ -0,0 +1,48 @@
+use log::kv::{Key, ToKey, ToValue, Value};
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> ToValue for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn to_value(&self) -> Value<'_> {
+        Value::from(self.as_ref())
+    }
+}
+
+impl<U> ToKey for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn to_key(&self) -> Key<'_> {
+        Key::from_str(self.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use log::kv::{ToKey, ToValue};
+
+    #[test]
+    fn wide_cow_kv() {
+        use crate::Cow;
+
+        let cow: Cow<str> = Cow::owned("foo".to_owned());
+
+        assert_eq!(cow.to_key().as_str(), "foo");
+        assert_eq!(cow.to_value().to_borrowed_str(), Some("foo"));
+    }
+
+    #[test]
+    fn lean_cow_kv() {
+        use crate::lean::Cow;
+
+        let cow: Cow<str> = Cow::borrowed("foo");
+
+        assert_eq!(cow.to_key().as_str(), "foo");
+        assert_eq!(cow.to_value().to_borrowed_str(), Some("foo"));
+    }
+}
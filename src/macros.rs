@@ -0,0 +1,309 @@
+/// Creates a [`Cow<[T]>`](./type.Cow.html), with the same syntax as `vec!`.
+///
+/// When all elements are literals the slice is promoted to a `'static` constant
+/// and the `Cow` borrows it, otherwise the elements are collected into an owned `Vec`.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::{cow_vec, Cow};
+///
+/// let borrowed: Cow<'static, [u8]> = cow_vec![1, 2, 3];
+/// let zeroes: Cow<[u8]> = cow_vec![0; 4];
+///
+/// assert!(borrowed.is_borrowed());
+/// assert_eq!(zeroes, &[0, 0, 0, 0][..]);
+///
+/// let x = 42;
+/// let owned: Cow<[u8]> = cow_vec![1, x];
+///
+/// assert!(owned.is_owned());
+/// ```
+#[macro_export]
+macro_rules! cow_vec {
+    ($elem:literal; $n:literal) => {
+        $crate::Cow::borrowed({
+            let slice: &'static [_] = &[$elem; $n];
+            slice
+        })
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::Cow::owned($crate::__private::vec![$elem; $n])
+    };
+    ($($x:literal),* $(,)?) => {
+        $crate::Cow::borrowed({
+            let slice: &'static [_] = &[$($x),*];
+            slice
+        })
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::Cow::owned($crate::__private::vec![$($x),+])
+    };
+}
+
+/// Concatenates values into a [`Cow<str>`](./type.Cow.html).
+///
+/// When all arguments are literals they are concatenated at compile time, same as
+/// `concat!`, into a borrowed `Cow<'static, str>`. Otherwise the arguments are
+/// concatenated at runtime into an owned `Cow`, with literals still rendered by
+/// `concat!`, so `1.0` is `"1.0"` either way, and any other expression formatted with
+/// `Display`.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::{cow_concat, Cow};
+///
+/// const GREETING: Cow<str> = cow_concat!("Hello", ' ', "World", '!');
+///
+/// assert_eq!(GREETING, "Hello World!");
+/// assert!(GREETING.is_borrowed());
+///
+/// let name = "beef";
+/// let owned: Cow<str> = cow_concat!("Hello ", name, '!');
+///
+/// assert_eq!(owned, "Hello beef!");
+/// assert!(owned.is_owned());
+/// ```
+#[macro_export]
+macro_rules! cow_concat {
+    ($($x:literal),* $(,)?) => {
+        $crate::Cow::const_str(::core::concat!($($x),*))
+    };
+    ($($x:tt)+) => {{
+        let mut buf = $crate::__private::String::new();
+        $crate::__cow_concat_push!(buf; $($x)+);
+        $crate::Cow::owned(buf)
+    }};
+}
+
+/// Appends the arguments of `cow_concat!` to a `String` one by one, so that literals
+/// can be told apart from other expressions.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cow_concat_push {
+    ($buf:ident; $(,)?) => {};
+    ($buf:ident; $x:literal $(, $($rest:tt)*)?) => {
+        $buf.push_str(::core::concat!($x));
+        $crate::__cow_concat_push!($buf; $($($rest)*)?);
+    };
+    ($buf:ident; $x:expr $(, $($rest:tt)*)?) => {
+        // Writing to a `String` never fails
+        let _ = ::core::fmt::Write::write_fmt(&mut $buf, ::core::format_args!("{}", $x));
+        $crate::__cow_concat_push!($buf; $($($rest)*)?);
+    };
+}
+
+/// Asserts that a [`Cow`](./type.Cow.html) is borrowed.
+///
+/// Use this in tests to prove that a code path never allocates owned data. Like
+/// `assert!`, a custom panic message can be provided after the `Cow`.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::{assert_borrowed, Cow};
+///
+/// fn trim(cow: Cow<str>) -> Cow<str> {
+///     if cow.trim().len() == cow.len() {
+///         cow
+///     } else {
+///         Cow::owned(cow.trim().to_string())
+///     }
+/// }
+///
+/// assert_borrowed!(trim(Cow::borrowed("beef")));
+/// assert_borrowed!(Cow::<str>::borrowed("beef"), "input was {}", "beef");
+/// ```
+///
+/// ```rust,should_panic
+/// # use beef::{assert_borrowed, Cow};
+/// assert_borrowed!(Cow::<str>::owned("beef".to_string()));
+/// ```
+#[macro_export]
+macro_rules! assert_borrowed {
+    ($cow:expr $(,)?) => {
+        match &$cow {
+            cow => {
+                if !cow.is_borrowed() {
+                    ::core::panic!(::core::concat!(
+                        "assertion failed: `",
+                        ::core::stringify!($cow),
+                        "` is borrowed"
+                    ));
+                }
+            }
+        }
+    };
+    ($cow:expr, $($arg:tt)+) => {
+        match &$cow {
+            cow => {
+                if !cow.is_borrowed() {
+                    ::core::panic!($($arg)+);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a [`Cow`](./type.Cow.html) is owned.
+///
+/// Like `assert!`, a custom panic message can be provided after the `Cow`.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::{assert_owned, Cow};
+///
+/// let cow: Cow<str> = Cow::owned("beef".to_string());
+///
+/// assert_owned!(cow);
+/// assert_owned!(cow, "expected {} to be owned", cow);
+/// ```
+#[macro_export]
+macro_rules! assert_owned {
+    ($cow:expr $(,)?) => {
+        match &$cow {
+            cow => {
+                if !cow.is_owned() {
+                    ::core::panic!(::core::concat!(
+                        "assertion failed: `",
+                        ::core::stringify!($cow),
+                        "` is owned"
+                    ));
+                }
+            }
+        }
+    };
+    ($cow:expr, $($arg:tt)+) => {
+        match &$cow {
+            cow => {
+                if !cow.is_owned() {
+                    ::core::panic!($($arg)+);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that a [`Cow`](./type.Cow.html) is borrowed, in debug builds only.
+///
+/// Same as [`assert_borrowed!`](./macro.assert_borrowed.html), but only enabled with
+/// `debug_assertions`, same as `debug_assert!`.
+#[macro_export]
+macro_rules! debug_assert_borrowed {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_borrowed!($($arg)*);
+        }
+    };
+}
+
+/// Asserts that a [`Cow`](./type.Cow.html) is owned, in debug builds only.
+///
+/// Same as [`assert_owned!`](./macro.assert_owned.html), but only enabled with
+/// `debug_assertions`, same as `debug_assert!`.
+#[macro_export]
+macro_rules! debug_assert_owned {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_owned!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Cow;
+
+    #[test]
+    fn cow_vec_literals() {
+        let empty: Cow<[u8]> = cow_vec![];
+        let cow: Cow<'static, [i32]> = cow_vec![1, -2, 3,];
+        let strs: Cow<[&str]> = cow_vec!["foo", "bar"];
+
+        assert!(empty.is_empty());
+        assert_eq!(cow, &[1, -2, 3][..]);
+        assert_eq!(strs, &["foo", "bar"][..]);
+        assert!(cow.is_borrowed());
+        assert!(strs.is_borrowed());
+    }
+
+    #[test]
+    fn cow_vec_exprs() {
+        let foo = String::from("foo");
+        let cow: Cow<[String]> = cow_vec![foo, "bar".to_owned()];
+
+        assert_eq!(cow, &["foo".to_owned(), "bar".to_owned()][..]);
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn cow_vec_repeat() {
+        let n = 3;
+        let zeroes: Cow<[u8]> = cow_vec![0; 3];
+        let strings: Cow<[String]> = cow_vec![String::from("foo"); n];
+
+        assert_eq!(zeroes, &[0, 0, 0][..]);
+        assert!(zeroes.is_borrowed());
+        assert_eq!(strings.len(), 3);
+        assert!(strings.is_owned());
+    }
+
+    #[test]
+    fn cow_concat_literals() {
+        const EMPTY: Cow<str> = cow_concat!();
+        const CONCAT: Cow<str> = cow_concat!("foo", 1, '-', true, 2.5,);
+
+        assert_eq!(EMPTY, "");
+        assert_eq!(CONCAT, "foo1-true2.5");
+        assert!(CONCAT.is_borrowed());
+    }
+
+    #[test]
+    fn cow_concat_exprs() {
+        let foo = "foo";
+        let bar = String::from("bar");
+        let cow: Cow<str> = cow_concat!(foo, '-', bar, 42, 1 + foo.len(),);
+
+        assert_eq!(cow, "foo-bar424");
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn cow_concat_literals_render_the_same() {
+        let x = "";
+
+        assert_eq!(cow_concat!(1.0, -2, true), "1.0-2true");
+        assert_eq!(cow_concat!(1.0, -2, true, x), "1.0-2true");
+        let mixed: Cow<str> = cow_concat!(x, 1e3, 2.50);
+        let literals: Cow<str> = cow_concat!(1e3, 2.50);
+
+        assert_eq!(mixed, literals);
+    }
+
+    #[test]
+    fn assert_borrowed_or_owned() {
+        let borrowed: Cow<str> = Cow::borrowed("foo");
+        let owned: Cow<str> = Cow::owned("bar".to_owned());
+
+        assert_borrowed!(borrowed);
+        assert_owned!(owned, "{} is not owned", owned);
+        debug_assert_borrowed!(borrowed,);
+        debug_assert_owned!(owned);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `Cow::<str>::borrowed(\"foo\")` is owned")]
+    fn assert_owned_panics() {
+        assert_owned!(Cow::<str>::borrowed("foo"));
+    }
+
+    #[test]
+    #[should_panic(expected = "bar is owned")]
+    fn assert_borrowed_panics_with_message() {
+        let owned: Cow<str> = Cow::owned("bar".to_owned());
+
+        assert_borrowed!(owned, "{} is owned", owned);
+    }
+}
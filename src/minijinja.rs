@@ -0,0 +1,96 @@
+use alloc::borrow::Cow as StdCow;
+use alloc::string::String;
+
+use minijinja::value::{ArgType, Value};
+use minijinja::Error;
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> From<Cow<'_, str, U>> for Value
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        Value::from(cow.as_ref())
+    }
+}
+
+impl<U> From<&Cow<'_, str, U>> for Value
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: &Cow<'_, str, U>) -> Self {
+        Value::from(cow.as_ref())
+    }
+}
+
+/// Stringifies the value, same as `From<Value> for String`.
+impl<U> From<Value> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(val: Value) -> Self {
+        Cow::owned(String::from(val))
+    }
+}
+
+/// Allows filters and functions to take `Cow<str>` arguments, borrowing from
+/// string values and stringifying others.
+impl<'a, U> ArgType<'a> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    type Output = Cow<'a, str, U>;
+
+    #[inline]
+    fn from_value(value: Option<&'a Value>) -> Result<Self::Output, Error> {
+        StdCow::<str>::from_value(value).map(Cow::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use minijinja::value::Value;
+    use minijinja::{context, Environment};
+
+    #[test]
+    fn wide_cow_into_value() {
+        use crate::Cow;
+
+        let env = Environment::new();
+        let name: Cow<'static, str> = Cow::borrowed("World");
+        let out = env
+            .render_str("Hello {{ name }}!", context! { name => name })
+            .unwrap();
+
+        assert_eq!(out, "Hello World!");
+
+        let cow: Cow<str> = Cow::from(Value::from(42));
+
+        assert_eq!(cow, "42");
+    }
+
+    #[test]
+    fn lean_cow_arg() {
+        use crate::lean::Cow;
+
+        fn shout(val: Cow<str>) -> String {
+            assert!(val.is_borrowed());
+
+            val.to_uppercase()
+        }
+
+        let mut env = Environment::new();
+
+        env.add_filter("shout", shout);
+
+        let out = env
+            .render_str("{{ name | shout }}", context! { name => "beef" })
+            .unwrap();
+
+        assert_eq!(out, "BEEF");
+    }
+}
@@ -0,0 +1,140 @@
+use alloc::borrow::Cow as StdCow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use mlua::{BString, BorrowedBytes, BorrowedStr, FromLua, IntoLua, Lua, Result, Value};
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> IntoLua for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        StdCow::from(self).into_lua(lua)
+    }
+}
+
+/// Byte `Cow`s are converted into Lua strings, rather than tables.
+impl<U> IntoLua for Cow<'_, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn into_lua(self, lua: &Lua) -> Result<Value> {
+        lua.create_string(&*self).map(Value::String)
+    }
+}
+
+/// `FromLua` takes the value by value, so there is nothing to borrow from, and the
+/// string is always copied into an owned `Cow`. To avoid the copy, borrow from the
+/// [`BorrowedStr`] returned by `LuaString::to_str` instead.
+impl<U> FromLua for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        String::from_lua(value, lua).map(Cow::owned)
+    }
+}
+
+/// Always copies, like the `str` impl. Borrow from the [`BorrowedBytes`] returned by
+/// `LuaString::as_bytes` to avoid the copy.
+impl<U> FromLua for Cow<'_, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from_lua(value: Value, lua: &Lua) -> Result<Self> {
+        BString::from_lua(value, lua).map(|bytes| Cow::owned(Vec::from(bytes)))
+    }
+}
+
+impl<'a, U> From<&'a BorrowedStr> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(string: &'a BorrowedStr) -> Self {
+        Cow::borrowed(string)
+    }
+}
+
+impl<'a, U> From<&'a BorrowedBytes> for Cow<'a, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(bytes: &'a BorrowedBytes) -> Self {
+        Cow::borrowed(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mlua::Lua;
+
+    #[test]
+    fn wide_cow_roundtrip() {
+        use crate::Cow;
+
+        let lua = Lua::new();
+        let globals = lua.globals();
+
+        globals.set("name", Cow::borrowed("beef")).unwrap();
+        globals
+            .set("bytes", Cow::borrowed(&b"\xFFbeef"[..]))
+            .unwrap();
+
+        let greeting: Cow<str> = lua.load(r#""Hello " .. name"#).eval().unwrap();
+
+        assert_eq!(greeting, "Hello beef");
+
+        let len: usize = lua.load("#bytes").eval().unwrap();
+        let bytes: Cow<[u8]> = globals.get("bytes").unwrap();
+
+        assert_eq!(len, 5);
+        assert_eq!(bytes, &b"\xFFbeef"[..]);
+    }
+
+    #[test]
+    fn lean_cow_from_number() {
+        use crate::lean::Cow;
+
+        let lua = Lua::new();
+        let cow: Cow<str> = lua.load("42").eval().unwrap();
+
+        assert_eq!(cow, "42");
+    }
+
+    #[test]
+    fn lean_cow_borrowed_from_string() {
+        use crate::lean::Cow;
+
+        let lua = Lua::new();
+        let string: mlua::LuaString = lua.load(r#""beef\xFF""#).eval().unwrap();
+
+        let bytes = string.as_bytes();
+        let bytes: Cow<[u8]> = Cow::from(&bytes);
+
+        assert!(bytes.is_borrowed());
+        assert_eq!(bytes, &b"beef\xFF"[..]);
+        assert!(string.to_str().is_err());
+    }
+
+    #[test]
+    fn wide_cow_borrowed_from_string() {
+        use crate::Cow;
+
+        let lua = Lua::new();
+        let string: mlua::LuaString = lua.load(r#""beef""#).eval().unwrap();
+
+        let str = string.to_str().unwrap();
+        let cow: Cow<str> = Cow::from(&str);
+
+        assert!(cow.is_borrowed());
+        assert_eq!(cow, "beef");
+    }
+}
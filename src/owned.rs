@@ -0,0 +1,239 @@
+//! Owned counterpart of the `Cow`, for data that must never be borrowed.
+
+use alloc::borrow::Borrow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::generic::Cow;
+use crate::traits::{Beef, Capacity};
+
+/// Owned data that is guaranteed to stay owned.
+///
+/// This is a transparent wrapper around `T::Owned` (`String` or `Vec<T>`), for
+/// struct fields that must own their data while still converting cheaply from and
+/// into `Cow`s. Unlike a `Cow<'static, T>`, it never has to check whether it owns
+/// its data, neither when dropped nor when unwrapped.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::{Cow, Owned};
+///
+/// let owned: Owned<str> = Owned::from(Cow::owned("Hello".to_string()));
+/// let string: String = owned.into_inner();
+///
+/// assert_eq!(string, "Hello");
+///
+/// let owned: Owned<str> = Owned::from(Cow::borrowed("World"));
+/// let cow: Cow<str> = owned.into();
+///
+/// assert!(cow.is_owned());
+/// ```
+#[repr(transparent)]
+pub struct Owned<T: Beef + ?Sized>(T::Owned);
+
+impl<T> Owned<T>
+where
+    T: Beef + ?Sized,
+{
+    /// Wraps owned data.
+    #[inline]
+    pub fn new(val: T::Owned) -> Self {
+        Owned(val)
+    }
+
+    /// Unwraps the owned data.
+    #[inline]
+    pub fn into_inner(self) -> T::Owned {
+        self.0
+    }
+}
+
+impl<T, U> From<Cow<'_, T, U>> for Owned<T>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    /// Takes over the buffer of an owned `Cow`, or clones borrowed data.
+    #[inline]
+    fn from(cow: Cow<'_, T, U>) -> Self {
+        Owned(cow.into_owned())
+    }
+}
+
+impl<'a, T, U> From<Owned<T>> for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn from(owned: Owned<T>) -> Self {
+        Cow::owned(owned.0)
+    }
+}
+
+impl From<String> for Owned<str> {
+    #[inline]
+    fn from(s: String) -> Self {
+        Owned(s)
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for Owned<[T]> {
+    #[inline]
+    fn from(v: Vec<T>) -> Self {
+        Owned(v)
+    }
+}
+
+impl<T> core::ops::Deref for Owned<T>
+where
+    T: Beef + ?Sized,
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.0.borrow()
+    }
+}
+
+impl<T> AsRef<T> for Owned<T>
+where
+    T: Beef + ?Sized,
+{
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.0.borrow()
+    }
+}
+
+impl<T> Borrow<T> for Owned<T>
+where
+    T: Beef + ?Sized,
+{
+    #[inline]
+    fn borrow(&self) -> &T {
+        self.0.borrow()
+    }
+}
+
+impl<T> Clone for Owned<T>
+where
+    T: Beef + ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Owned(self.0.borrow().to_owned())
+    }
+}
+
+impl<T> Default for Owned<T>
+where
+    T: Beef + ?Sized,
+    T::Owned: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Owned(T::Owned::default())
+    }
+}
+
+impl<T> Hash for Owned<T>
+where
+    T: Beef + Hash + ?Sized,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<T> PartialEq for Owned<T>
+where
+    T: Beef + PartialEq + ?Sized,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T> Eq for Owned<T> where T: Beef + Eq + ?Sized {}
+
+impl<T> PartialOrd for Owned<T>
+where
+    T: Beef + PartialOrd + ?Sized,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T> Ord for Owned<T>
+where
+    T: Beef + Ord + ?Sized,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T> fmt::Debug for Owned<T>
+where
+    T: Beef + fmt::Debug + ?Sized,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T> fmt::Display for Owned<T>
+where
+    T: Beef + fmt::Display + ?Sized,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Owned;
+
+    #[test]
+    fn wide_cow_into_owned() {
+        use crate::Cow;
+
+        let owned = Owned::<str>::from(Cow::borrowed("foo"));
+        let cow: Cow<str> = owned.clone().into();
+
+        assert_eq!(&*owned, "foo");
+        assert_eq!(cow, "foo");
+        assert!(cow.is_owned());
+        assert_eq!(owned.into_inner(), "foo");
+    }
+
+    #[test]
+    fn lean_cow_into_owned() {
+        use crate::lean::Cow;
+
+        let vec = vec![1u8, 2, 3];
+        let ptr = vec.as_ptr();
+        let owned = Owned::<[u8]>::from(Cow::owned(vec));
+
+        assert_eq!(owned.as_ptr(), ptr);
+        assert_eq!(owned, Owned::from(vec![1, 2, 3]));
+
+        let cow: Cow<[u8]> = owned.into();
+
+        assert_eq!((*cow).as_ptr(), ptr);
+    }
+}
@@ -0,0 +1,266 @@
+//! Tracks API parity with `std::borrow::Cow`: every snippet in here is compiled
+//! and run against `std::borrow::Cow`, `beef::Cow` and `beef::lean::Cow` alike.
+
+#[rustfmt::skip]
+macro_rules! parity {
+    ($($name:ident $body:block)*) => {
+        mod std_cow {
+            use std::borrow::Cow;
+
+            $( #[test] fn $name() $body )*
+        }
+
+        mod wide_cow {
+            use crate::Cow;
+
+            $( #[test] fn $name() $body )*
+        }
+
+        mod lean_cow {
+            use crate::lean::Cow;
+
+            $( #[test] fn $name() $body )*
+        }
+    };
+}
+
+parity! {
+    from_str_refs {
+        let s = String::from("foo");
+        let a: Cow<str> = Cow::from("foo");
+        let b: Cow<str> = Cow::from(&s);
+        let c: Cow<str> = "foo".into();
+
+        assert_eq!(a, b);
+        assert_eq!(b, c);
+    }
+
+    from_slice_refs {
+        let v = vec![1, 2, 3];
+        let a: Cow<[i32]> = Cow::from(&v);
+        let b: Cow<[i32]> = Cow::from(&v[..]);
+        let c: Cow<[i32]> = Cow::from(&[1, 2, 3]);
+
+        assert_eq!(a, b);
+        assert_eq!(b, c);
+    }
+
+    generic_into_from_refs {
+        fn name<'a>(name: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
+            name.into()
+        }
+
+        fn items<'a>(items: impl Into<Cow<'a, [u8]>>) -> Cow<'a, [u8]> {
+            items.into()
+        }
+
+        let s = String::from("foo");
+        let v = vec![1, 2];
+
+        let borrowed = name(&s);
+        let borrowed: &str = &borrowed;
+
+        assert_eq!(borrowed.as_ptr(), s.as_ptr());
+        assert_eq!(items(&v), &[1, 2][..]);
+    }
+
+    from_owned {
+        let a: Cow<str> = Cow::from(String::from("foo"));
+        let b: Cow<[u8]> = Cow::from(vec![1, 2, 3]);
+
+        assert_eq!(a, "foo");
+        assert_eq!(b, &[1, 2, 3][..]);
+    }
+
+    into_owned {
+        let a: Cow<str> = Cow::from("foo");
+        let b: Cow<[u8]> = Cow::from(vec![1, 2, 3]);
+
+        assert_eq!(a.into_owned(), "foo");
+        assert_eq!(b.into_owned(), vec![1, 2, 3]);
+    }
+
+    to_mut {
+        let mut a: Cow<str> = Cow::from("foo");
+        let mut b: Cow<[u8]> = Cow::from(vec![1, 2]);
+
+        a.to_mut().push_str("bar");
+        b.to_mut().push(3);
+
+        assert_eq!(a, "foobar");
+        assert_eq!(b, &[1, 2, 3][..]);
+    }
+
+    deref_as_ref_borrow {
+        use std::borrow::Borrow;
+
+        let a: Cow<str> = Cow::from("foo");
+        let borrowed: &str = a.borrow();
+
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.as_ref(), "foo");
+        assert_eq!(borrowed, "foo");
+        assert!(a.starts_with('f'));
+    }
+
+    as_ref_targets_through_deref {
+        fn path_len<P: AsRef<std::path::Path>>(path: P) -> usize {
+            path.as_ref().as_os_str().len()
+        }
+
+        fn byte_len<B: AsRef<[u8]>>(bytes: B) -> usize {
+            bytes.as_ref().len()
+        }
+
+        // Neither `Cow` implements `AsRef<Path>`, `AsRef<OsStr>` or `AsRef<[u8]>`, as
+        // it would break inference of `cow.as_ref()`, but the deref'd `str` does.
+        let a: Cow<str> = Cow::from("src/lib.rs");
+
+        assert_eq!(path_len(&*a), 10);
+        assert_eq!(byte_len(&*a), 10);
+        assert_eq!(std::ffi::OsStr::new(&*a), "src/lib.rs");
+    }
+
+    clone_default {
+        let a: Cow<str> = Cow::from(String::from("foo"));
+        let b: Cow<[u8]> = Cow::default();
+        let c: Cow<str> = Default::default();
+
+        assert_eq!(a.clone(), a);
+        assert!(b.is_empty());
+        assert_eq!(c, "");
+    }
+
+    fmt {
+        let a: Cow<str> = Cow::from("foo");
+        let b: Cow<[u8]> = Cow::from(&[1, 2][..]);
+
+        assert_eq!(format!("{} {:?} {:?}", a, a, b), "foo \"foo\" [1, 2]");
+        assert_eq!(a.to_string(), "foo");
+    }
+
+    hash_lookup {
+        use std::collections::HashMap;
+
+        let mut map: HashMap<Cow<str>, i32> = HashMap::new();
+
+        map.insert(Cow::from("foo"), 1);
+        map.insert(Cow::from(String::from("bar")), 2);
+
+        assert_eq!(map.get("foo"), Some(&1));
+        assert_eq!(map.get("bar"), Some(&2));
+    }
+
+    compare {
+        let a: Cow<str> = Cow::from("a");
+        let b: Cow<str> = Cow::from(String::from("b"));
+        let string = String::from("b");
+        let vec = vec![1, 2];
+        let mut arr = [1, 2];
+        let s: Cow<[i32]> = Cow::from(&[1, 2][..]);
+
+        assert!(a < b);
+        assert_eq!(a.cmp(&b), std::cmp::Ordering::Less);
+        assert_eq!(a, "a");
+        assert_eq!("a", a);
+        assert_eq!(a, *"a");
+        assert_eq!(*"b", b);
+        assert_eq!(b, string);
+        assert_eq!(string, b);
+        assert_eq!(s, &[1, 2][..]);
+        assert_eq!(s, vec);
+        assert_eq!(s, &mut arr[..]);
+    }
+
+    sort_and_btree_keys {
+        use std::collections::BTreeMap;
+
+        let mut cows: Vec<Cow<str>> = vec![
+            Cow::from(String::from("pear")),
+            Cow::from("apple"),
+            Cow::from(String::from("fig")),
+        ];
+        let mut map: BTreeMap<Cow<str>, i32> = BTreeMap::new();
+
+        cows.sort();
+        map.insert(Cow::from("b"), 2);
+        map.insert(Cow::from(String::from("a")), 1);
+
+        assert_eq!(cows, ["apple", "fig", "pear"]);
+        assert_eq!(map.keys().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(map.get("b"), Some(&2));
+        assert_eq!(cows.iter().max().unwrap(), "pear");
+    }
+
+    compare_with_std_cow {
+        let a: Cow<str> = Cow::from("a");
+        let b: std::borrow::Cow<str> = std::borrow::Cow::Owned(String::from("b"));
+        let s: Cow<[u8]> = Cow::from(vec![1, 2]);
+        let t: std::borrow::Cow<[u8]> = std::borrow::Cow::Borrowed(&[1, 2]);
+
+        assert!(a != b);
+        assert!(b != a);
+        assert!(a < b);
+        assert!(b > a);
+        assert!(s == t);
+        assert!(t == s);
+        assert!(s <= t);
+    }
+
+    add {
+        let a: Cow<str> = Cow::from("");
+        let a = a + "foo";
+        let mut b = a.clone() + Cow::from("bar");
+
+        b += "baz";
+        b += Cow::from(String::from("!"));
+
+        assert_eq!(a, "foo");
+        assert_eq!(b, "foobarbaz!");
+    }
+
+    into_std_types {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let a: Cow<str> = Cow::from("foo");
+        let b: Cow<[u8]> = Cow::from(vec![1, 2]);
+
+        assert_eq!(String::from(a.clone()), "foo");
+        assert_eq!(&*Box::<str>::from(a.clone()), "foo");
+        assert_eq!(&*Arc::<str>::from(a.clone()), "foo");
+        assert_eq!(&*Rc::<str>::from(a.clone()), "foo");
+        assert_eq!(Vec::from(b.clone()), [1, 2]);
+        assert_eq!(&*Box::<[u8]>::from(b.clone()), [1, 2]);
+        assert_eq!(&*Arc::<[u8]>::from(b.clone()), [1, 2]);
+        assert_eq!(&*Rc::<[u8]>::from(b), [1, 2]);
+
+        #[cfg(feature = "std")]
+        {
+            let err: Box<dyn std::error::Error + Send + Sync> = a.into();
+
+            assert_eq!(err.to_string(), "foo");
+        }
+    }
+
+    from_iter {
+        let a: Cow<str> = "foo".chars().collect();
+        let b: Cow<str> = vec!["foo", "bar"].into_iter().collect();
+        let c: Cow<str> = vec![String::from("baz")].into_iter().collect();
+        let d: Cow<[u8]> = (1..4).collect();
+
+        assert_eq!(a, "foo");
+        assert_eq!(b, "foobar");
+        assert_eq!(c, "baz");
+        assert_eq!(d, &[1, 2, 3][..]);
+    }
+
+    collect_into_string {
+        let parts: Vec<Cow<str>> = vec![Cow::from("foo"), Cow::from(String::from("bar"))];
+        let mut s: String = parts.clone().into_iter().collect();
+
+        s.extend(parts);
+
+        assert_eq!(s, "foobarfoobar");
+    }
+}
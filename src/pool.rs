@@ -0,0 +1,63 @@
+//! A bounded pool of leaked values, which `'static` borrowed `Cow`s generated for
+//! property tests borrow from.
+//!
+//! Every value leaked here stays reachable from `POOL`, so neither LeakSanitizer nor
+//! Miri report it, and at most `POOL_SIZE` values are ever leaked by the process. Once
+//! the pool is full, the value is handed back, and the caller should own it instead.
+//! Empty values don't allocate, and never take up room in the pool.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+const POOL_SIZE: usize = 1024;
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY: AtomicPtr<u8> = AtomicPtr::new(ptr::null_mut());
+
+static POOL: [AtomicPtr<u8>; POOL_SIZE] = [EMPTY; POOL_SIZE];
+static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+#[inline]
+fn reserve() -> Option<&'static AtomicPtr<u8>> {
+    // Stop counting once the pool is full, so `NEXT` can't overflow
+    NEXT.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |next| {
+        (next < POOL_SIZE).then_some(next + 1)
+    })
+    .ok()
+    .map(|next| &POOL[next])
+}
+
+/// Leaks `value` into the pool, or hands it back if the pool is full.
+pub(crate) fn leak_str(value: String) -> Result<&'static str, String> {
+    if value.is_empty() {
+        return Ok("");
+    }
+
+    let slot = match reserve() {
+        Some(slot) => slot,
+        None => return Err(value),
+    };
+    let leaked = Box::leak(value.into_boxed_str());
+
+    slot.store(leaked.as_mut_ptr(), Ordering::Relaxed);
+
+    Ok(leaked)
+}
+
+/// Leaks `value` into the pool, or hands it back if the pool is full.
+pub(crate) fn leak_slice<T: 'static>(value: Vec<T>) -> Result<&'static [T], Vec<T>> {
+    if value.is_empty() {
+        return Ok(&[]);
+    }
+
+    let slot = match reserve() {
+        Some(slot) => slot,
+        None => return Err(value),
+    };
+    let leaked = Box::leak(value.into_boxed_slice());
+
+    slot.store(leaked.as_mut_ptr().cast(), Ordering::Relaxed);
+
+    Ok(leaked)
+}
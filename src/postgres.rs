@@ -0,0 +1,85 @@
+use alloc::boxed::Box;
+use std::error::Error;
+
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::generic::{Capacity, Cow};
+use crate::traits::Beef;
+
+type BoxError = Box<dyn Error + Sync + Send>;
+
+impl<'a, T, U> FromSql<'a> for Cow<'a, T, U>
+where
+    T: Beef + ?Sized,
+    &'a T: FromSql<'a>,
+    U: Capacity,
+{
+    #[inline]
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, BoxError> {
+        <&'a T>::from_sql(ty, raw).map(Cow::borrowed)
+    }
+
+    #[inline]
+    fn accepts(ty: &Type) -> bool {
+        <&'a T>::accepts(ty)
+    }
+}
+
+impl<T, U> ToSql for Cow<'_, T, U>
+where
+    T: Beef + core::fmt::Debug + ?Sized,
+    for<'b> &'b T: ToSql,
+    U: Capacity,
+{
+    #[inline]
+    fn to_sql(&self, ty: &Type, out: &mut bytes::BytesMut) -> Result<IsNull, BoxError> {
+        self.as_ref().to_sql(ty, out)
+    }
+
+    #[inline]
+    fn accepts(ty: &Type) -> bool {
+        <&T>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+#[cfg(test)]
+mod tests {
+    use postgres_types::{FromSql, ToSql, Type};
+
+    #[test]
+    fn wide_cow_postgres() {
+        use crate::Cow;
+
+        let cow = Cow::<str>::from_sql(&Type::TEXT, b"foo").unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_borrowed());
+        assert!(!<Cow<str> as FromSql>::accepts(&Type::BYTEA));
+
+        let mut out = Default::default();
+
+        cow.to_sql_checked(&Type::VARCHAR, &mut out).unwrap();
+
+        assert_eq!(&out[..], b"foo");
+    }
+
+    #[test]
+    fn lean_cow_postgres() {
+        use crate::lean::Cow;
+
+        let cow = Cow::<[u8]>::from_sql(&Type::BYTEA, &[1, 2, 3]).unwrap();
+
+        assert_eq!(cow, &[1, 2, 3][..]);
+        assert!(cow.is_borrowed());
+
+        let mut out = Default::default();
+
+        assert!(cow.to_sql_checked(&Type::TEXT, &mut out).is_err());
+
+        cow.to_sql_checked(&Type::BYTEA, &mut out).unwrap();
+
+        assert_eq!(&out[..], &[1, 2, 3]);
+    }
+}
@@ -0,0 +1,252 @@
+//! [proptest](https://docs.rs/proptest) strategies generating a mix of borrowed and
+//! owned `Cow`s, so that property tests cover both states.
+//!
+//! `any::<Cow<'static, str>>()` and `any::<Cow<'static, [T]>>()` use the strategies
+//! in this module. Since generated values must be `'static`, a borrowed `Cow` borrows
+//! from an allocation leaked into a bounded pool, which keeps it reachable so that
+//! LeakSanitizer and Miri don't report it. At most 1024 values are leaked per process,
+//! after which only owned `Cow`s are generated. Each case leaks at most once, and
+//! shrinking never leaks, so shrunk values are always owned.
+//!
+//! ```rust
+//! use beef::Cow;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn roundtrip(cow in beef::proptest::cow_str("[a-z]*")) {
+//!         let cow: Cow<str> = cow;
+//!         let string = cow.clone().into_owned();
+//!
+//!         prop_assert_eq!(cow, string);
+//!     }
+//! }
+//!
+//! roundtrip();
+//! ```
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::cell::Cell;
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{BoxedStrategy, NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+use crate::generic::{Capacity, Cow};
+use crate::pool;
+use crate::traits::Beef;
+
+type Leak<T> = fn(<T as ToOwned>::Owned) -> Result<&'static T, <T as ToOwned>::Owned>;
+
+/// Generates `Cow<str>`s from the strings produced by `inner`, either owning them or
+/// borrowing them from a leaked allocation.
+pub fn cow_str<S, U>(inner: S) -> impl Strategy<Value = Cow<'static, str, U>>
+where
+    S: Strategy,
+    S::Value: Into<String>,
+    U: Capacity + 'static,
+{
+    CowStrategy {
+        inner: inner.prop_map(Into::into),
+        leak: pool::leak_str,
+        marker: PhantomData,
+    }
+}
+
+/// Generates `Cow<[T]>`s from the vectors produced by `inner`, either owning them or
+/// borrowing them from a leaked allocation.
+pub fn cow_slice<S, T, U>(inner: S) -> impl Strategy<Value = Cow<'static, [T], U>>
+where
+    S: Strategy<Value = Vec<T>>,
+    T: Clone + Debug + 'static,
+    U: Capacity + 'static,
+{
+    CowStrategy {
+        inner,
+        leak: pool::leak_slice,
+        marker: PhantomData,
+    }
+}
+
+struct CowStrategy<S, T: Beef + ?Sized + 'static, U> {
+    inner: S,
+    leak: Leak<T>,
+    marker: PhantomData<fn() -> U>,
+}
+
+impl<S: Debug, T: Beef + ?Sized, U> Debug for CowStrategy<S, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CowStrategy")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<S, T, U> Strategy for CowStrategy<S, T, U>
+where
+    S: Strategy<Value = T::Owned>,
+    T: Beef + Debug + ?Sized + 'static,
+    U: Capacity,
+{
+    type Tree = CowTree<S::Tree, T, U>;
+    type Value = Cow<'static, T, U>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        Ok(CowTree {
+            owned: any::<bool>().new_tree(runner)?.current(),
+            inner: self.inner.new_tree(runner)?,
+            leaked: Cell::new(None),
+            leak: self.leak,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Leaks the generated value the first time a borrowed `Cow` of it is requested, and
+/// reuses that leak afterwards. Once shrinking starts, values are always owned.
+struct CowTree<V, T: Beef + ?Sized + 'static, U> {
+    inner: V,
+    owned: bool,
+    leaked: Cell<Option<&'static T>>,
+    leak: Leak<T>,
+    marker: PhantomData<fn() -> U>,
+}
+
+impl<V: Debug, T: Beef + Debug + ?Sized, U> Debug for CowTree<V, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CowTree")
+            .field("inner", &self.inner)
+            .field("owned", &self.owned)
+            .field("leaked", &self.leaked.get())
+            .finish()
+    }
+}
+
+impl<V, T, U> ValueTree for CowTree<V, T, U>
+where
+    V: ValueTree<Value = T::Owned>,
+    T: Beef + Debug + ?Sized + 'static,
+    U: Capacity,
+{
+    type Value = Cow<'static, T, U>;
+
+    fn current(&self) -> Self::Value {
+        if self.owned {
+            return Cow::owned(self.inner.current());
+        }
+
+        if let Some(leaked) = self.leaked.get() {
+            return Cow::borrowed(leaked);
+        }
+
+        match (self.leak)(self.inner.current()) {
+            Ok(leaked) => {
+                self.leaked.set(Some(leaked));
+
+                Cow::borrowed(leaked)
+            }
+            Err(value) => Cow::owned(value),
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.owned = true;
+        self.inner.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.owned = true;
+        self.inner.complicate()
+    }
+}
+
+impl<U> Arbitrary for Cow<'static, str, U>
+where
+    U: Capacity + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        cow_str(any::<String>()).boxed()
+    }
+}
+
+impl<T, U> Arbitrary for Cow<'static, [T], U>
+where
+    T: Arbitrary + Clone + 'static,
+    U: Capacity + 'static,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        cow_slice(proptest::collection::vec(T::arbitrary_with(args), 0..64)).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn wide_cow_proptest(cow in any::<crate::Cow<'static, str>>()) {
+            prop_assert_eq!(cow.is_borrowed(), !cow.is_owned());
+            prop_assert_eq!(&*cow.clone().into_owned(), &*cow);
+        }
+
+        #[test]
+        fn lean_cow_proptest(cow in super::cow_slice(proptest::collection::vec(any::<u8>(), 0..8))) {
+            let cow: crate::lean::Cow<[u8]> = cow;
+
+            prop_assert!(cow.len() < 8);
+        }
+    }
+
+    #[test]
+    fn generates_both_states() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let strategy = any::<crate::Cow<'static, [u8]>>();
+        let mut runner = TestRunner::deterministic();
+        let (mut borrowed, mut owned) = (false, false);
+
+        for _ in 0..64 {
+            let cow = strategy.new_tree(&mut runner).unwrap().current();
+
+            borrowed |= cow.is_borrowed();
+            owned |= cow.is_owned();
+        }
+
+        assert!(borrowed && owned);
+    }
+
+    #[test]
+    fn leaks_once_and_shrinks_to_owned() {
+        use proptest::strategy::ValueTree;
+        use proptest::test_runner::TestRunner;
+
+        let strategy = any::<crate::Cow<'static, str>>();
+        let mut runner = TestRunner::deterministic();
+
+        let mut tree = loop {
+            let tree = strategy.new_tree(&mut runner).unwrap();
+
+            if tree.current().is_borrowed() {
+                break tree;
+            }
+        };
+
+        assert_eq!(tree.current().as_ptr(), tree.current().as_ptr());
+
+        // An empty `String` doesn't allocate, so it's stored as borrowed
+        while tree.simplify() {
+            let cow = tree.current();
+
+            assert!(cow.is_owned() || cow.is_empty());
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! `quickcheck::Arbitrary` for `Cow<'static, str>` and `Cow<'static, [T]>`.
+//!
+//! Values must be `'static`, so borrowed `Cow`s borrow from allocations that are leaked
+//! into a bounded pool, which keeps them reachable so that LeakSanitizer and Miri
+//! don't report them. At most 1024 values are leaked per process, after which only
+//! owned `Cow`s are generated.
+//!
+//! Shrinking never leaks, so shrunk candidates are always owned.
+
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::generic::{Capacity, Cow};
+use crate::pool;
+
+#[inline]
+fn str_cow<U: Capacity>(owned: bool, value: String) -> Cow<'static, str, U> {
+    if owned {
+        return Cow::owned(value);
+    }
+
+    match pool::leak_str(value) {
+        Ok(leaked) => Cow::borrowed(leaked),
+        Err(value) => Cow::owned(value),
+    }
+}
+
+#[inline]
+fn slice_cow<T: Clone + 'static, U: Capacity>(owned: bool, value: Vec<T>) -> Cow<'static, [T], U> {
+    if owned {
+        return Cow::owned(value);
+    }
+
+    match pool::leak_slice(value) {
+        Ok(leaked) => Cow::borrowed(leaked),
+        Err(value) => Cow::owned(value),
+    }
+}
+
+impl<U> Arbitrary for Cow<'static, str, U>
+where
+    U: Capacity + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        str_cow(bool::arbitrary(g), String::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.as_ref().to_owned().shrink().map(Cow::owned))
+    }
+}
+
+impl<T, U> Arbitrary for Cow<'static, [T], U>
+where
+    T: Arbitrary,
+    U: Capacity + 'static,
+{
+    fn arbitrary(g: &mut Gen) -> Self {
+        slice_cow(bool::arbitrary(g), Vec::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.as_ref().to_vec().shrink().map(Cow::owned))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen};
+
+    #[test]
+    fn wide_cow_quickcheck() {
+        use crate::Cow;
+
+        let mut g = Gen::new(16);
+        let (mut borrowed, mut owned) = (false, false);
+
+        for _ in 0..64 {
+            let cow = Cow::<'static, str>::arbitrary(&mut g);
+
+            borrowed |= cow.is_borrowed();
+            owned |= cow.is_owned();
+        }
+
+        assert!(borrowed && owned);
+    }
+
+    #[test]
+    fn lean_cow_shrink() {
+        use crate::lean::Cow;
+
+        let cow: Cow<'static, [u8]> = Cow::borrowed(&[3, 4]);
+
+        // An empty `Vec` doesn't allocate, so it's stored as borrowed
+        assert!(cow
+            .shrink()
+            .all(|shrunk| shrunk.len() <= 2 && (shrunk.is_owned() || shrunk.is_empty())));
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> ToRedisArgs for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.as_bytes())
+    }
+}
+
+impl<U> ToSingleRedisArg for Cow<'_, str, U> where U: Capacity {}
+
+impl<U> ToRedisArgs for Cow<'_, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self)
+    }
+}
+
+impl<U> ToSingleRedisArg for Cow<'_, [u8], U> where U: Capacity {}
+
+// Values are parsed into owned buffers, consuming the reply where possible.
+impl<U> FromRedisValue for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        String::from_redis_value_ref(v).map(Cow::owned)
+    }
+
+    #[inline]
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        String::from_redis_value(v).map(Cow::owned)
+    }
+}
+
+impl<U> FromRedisValue for Cow<'_, [u8], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        Vec::<u8>::from_redis_value_ref(v).map(Cow::owned)
+    }
+
+    #[inline]
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        Vec::<u8>::from_redis_value(v).map(Cow::owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use redis::{FromRedisValue, ToRedisArgs, Value};
+
+    #[test]
+    fn wide_cow_to_args() {
+        use crate::Cow;
+
+        let cow: Cow<str> = Cow::borrowed("foo");
+
+        assert_eq!(cow.to_redis_args(), vec![b"foo".to_vec()]);
+
+        let cow: Cow<[u8]> = Cow::owned(b"bar".to_vec());
+
+        assert_eq!(cow.to_redis_args(), vec![b"bar".to_vec()]);
+    }
+
+    #[test]
+    fn lean_cow_from_value() {
+        use crate::lean::Cow;
+
+        let value = Value::BulkString(b"foo".to_vec());
+        let cow = Cow::<str>::from_redis_value_ref(&value).unwrap();
+
+        assert_eq!(cow, "foo");
+
+        let cow = Cow::<[u8]>::from_redis_value(value).unwrap();
+
+        assert_eq!(cow, &b"foo"[..]);
+        assert!(cow.is_owned());
+
+        assert!(Cow::<str>::from_redis_value(Value::BulkString(vec![0xFF])).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use alloc::{string::String, vec::Vec};
+
+use rkyv::rancor::{Fallible, Source};
+use rkyv::ser::{Allocator, Writer};
+use rkyv::string::{ArchivedString, StringResolver};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Deserialize, DeserializeUnsized, Place, Serialize, SerializeUnsized};
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> Archive for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedString::resolve_from_str(self.as_ref(), resolver, out);
+    }
+}
+
+impl<U, S> Serialize<S> for Cow<'_, str, U>
+where
+    U: Capacity,
+    S: Fallible + ?Sized,
+    S::Error: Source,
+    str: SerializeUnsized<S>,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self.as_ref(), serializer)
+    }
+}
+
+impl<'a, U, D> Deserialize<Cow<'a, str, U>, D> for ArchivedString
+where
+    U: Capacity,
+    D: Fallible + ?Sized,
+    str: DeserializeUnsized<str, D>,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<Cow<'a, str, U>, D::Error> {
+        Deserialize::<String, D>::deserialize(self, deserializer).map(Cow::owned)
+    }
+}
+
+impl<T, U> Archive for Cow<'_, [T], U>
+where
+    T: Archive + Clone,
+    U: Capacity,
+{
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self.as_ref(), resolver, out);
+    }
+}
+
+impl<T, U, S> Serialize<S> for Cow<'_, [T], U>
+where
+    T: Serialize<S> + Clone,
+    U: Capacity,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self.as_ref(), serializer)
+    }
+}
+
+impl<'a, T, U, D> Deserialize<Cow<'a, [T], U>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive + Clone,
+    [T::Archived]: DeserializeUnsized<[T], D>,
+    U: Capacity,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut D) -> Result<Cow<'a, [T], U>, D::Error> {
+        Deserialize::<Vec<T>, D>::deserialize(self, deserializer).map(Cow::owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+    use rkyv::string::ArchivedString;
+    use rkyv::vec::ArchivedVec;
+    use rkyv::Archived;
+
+    #[test]
+    fn wide_cow_rkyv() {
+        use crate::Cow;
+
+        let cow: Cow<str> = Cow::borrowed("foo");
+        let bytes = rkyv::to_bytes::<Error>(&cow).unwrap();
+
+        // Safety: the bytes were just serialized from a `Cow<str>`
+        let archived = unsafe { rkyv::access_unchecked::<ArchivedString>(&bytes) };
+
+        assert_eq!(archived.as_str(), "foo");
+
+        let cow: Cow<str> = rkyv::deserialize::<_, Error>(archived).unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn lean_cow_rkyv() {
+        use crate::lean::Cow;
+
+        let cow: Cow<[u32]> = Cow::borrowed(&[1, 2, 3]);
+        let bytes = rkyv::to_bytes::<Error>(&cow).unwrap();
+
+        // Safety: the bytes were just serialized from a `Cow<[u32]>`
+        let archived = unsafe { rkyv::access_unchecked::<ArchivedVec<Archived<u32>>>(&bytes) };
+
+        assert_eq!(archived.as_slice(), &[1, 2, 3]);
+
+        let cow: Cow<[u32]> = rkyv::deserialize::<_, Error>(archived).unwrap();
+
+        assert_eq!(cow, &[1, 2, 3][..]);
+    }
+}
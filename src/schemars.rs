@@ -0,0 +1,55 @@
+use alloc::borrow::Cow as StdCow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+use crate::generic::{Capacity, Cow};
+use crate::traits::Beef;
+
+// Same as the impl for `std::borrow::Cow`, the schema is that of `T`, which in turn
+// matches `String` and `Vec<T>`.
+impl<T, U> JsonSchema for Cow<'_, T, U>
+where
+    T: Beef + JsonSchema + ?Sized,
+    U: Capacity,
+{
+    #[inline]
+    fn inline_schema() -> bool {
+        T::inline_schema()
+    }
+
+    #[inline]
+    fn schema_name() -> StdCow<'static, str> {
+        T::schema_name()
+    }
+
+    #[inline]
+    fn schema_id() -> StdCow<'static, str> {
+        T::schema_id()
+    }
+
+    #[inline]
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        T::json_schema(generator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::schema_for;
+
+    #[test]
+    fn wide_cow_schema() {
+        use crate::Cow;
+
+        assert_eq!(schema_for!(Cow<str>), schema_for!(String));
+        assert_eq!(schema_for!(Cow<[u32]>), schema_for!(Vec<u32>));
+    }
+
+    #[test]
+    fn lean_cow_schema() {
+        use crate::lean::Cow;
+
+        assert_eq!(schema_for!(Cow<str>), schema_for!(String));
+        assert_eq!(schema_for!(Cow<[u8]>), schema_for!(Vec<u8>));
+    }
+}
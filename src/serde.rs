@@ -1,3 +1,6 @@
+//! `Serialize` and `Deserialize` impls for `Cow`, and the [`bytes`](./bytes/index.html)
+//! helper module for byte slices.
+
 use alloc::{borrow::ToOwned, string::String};
 use core::{fmt, marker::PhantomData};
 
@@ -5,7 +8,7 @@ use serde::de::{self, Deserialize, Deserializer, Visitor};
 use serde::ser::{Serialize, Serializer};
 
 use crate::generic::Cow;
-use crate::traits::internal::{Beef, Capacity};
+use crate::traits::{Beef, Capacity};
 
 impl<T, U> Serialize for Cow<'_, T, U>
 where
@@ -20,11 +23,9 @@ where
     }
 }
 
-struct CowVisitor<'de, 'a, T: Beef + ?Sized, U: Capacity>(
-    PhantomData<fn() -> (&'de T, Cow<'a, T, U>)>,
-);
+struct CowVisitor<'a, T: Beef + ?Sized, U: Capacity>(PhantomData<fn() -> Cow<'a, T, U>>);
 
-impl<'de, 'a, U> Visitor<'de> for CowVisitor<'de, 'a, str, U>
+impl<'de, 'a, U> Visitor<'de> for CowVisitor<'a, str, U>
 where
     'de: 'a,
     U: Capacity,
@@ -67,7 +68,7 @@ where
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_str(CowVisitor::<'de, 'a, str, U>(PhantomData))
+        deserializer.deserialize_str(CowVisitor::<'a, str, U>(PhantomData))
     }
 }
 
@@ -86,6 +87,129 @@ where
     }
 }
 
+/// Serializes `Cow<[u8]>` as a byte string, instead of a sequence of integers, for use
+/// with `#[serde(with = "beef::serde::bytes")]`.
+///
+/// Deserialization borrows from the input whenever the deserializer hands out borrowed
+/// bytes, and otherwise also accepts strings and sequences of bytes.
+///
+/// ```rust
+/// use beef::Cow;
+/// use serde_derive::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Message<'a> {
+///     #[serde(borrow, with = "beef::serde::bytes")]
+///     payload: Cow<'a, [u8]>,
+/// }
+///
+/// let msg: Message = serde_json::from_str(r#"{"payload":"hello"}"#).unwrap();
+///
+/// assert_eq!(msg.payload, &b"hello"[..]);
+/// assert!(msg.payload.is_borrowed());
+/// ```
+pub mod bytes {
+    use alloc::vec::Vec;
+    use core::{fmt, marker::PhantomData};
+
+    use serde::de::{self, Deserializer, SeqAccess, Visitor};
+    use serde::ser::Serializer;
+
+    use crate::generic::Cow;
+    use crate::traits::Capacity;
+
+    /// Serializes the `Cow` with `Serializer::serialize_bytes`.
+    #[inline]
+    pub fn serialize<S, U>(cow: &Cow<'_, [u8], U>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        U: Capacity,
+    {
+        serializer.serialize_bytes(cow.as_ref())
+    }
+
+    /// Deserializes a `Cow` with `Deserializer::deserialize_bytes`, borrowing from the
+    /// input when possible.
+    #[inline]
+    pub fn deserialize<'de, 'a, D, U>(deserializer: D) -> Result<Cow<'a, [u8], U>, D::Error>
+    where
+        'de: 'a,
+        D: Deserializer<'de>,
+        U: Capacity,
+    {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
+
+    struct BytesVisitor<'a, U>(PhantomData<(&'a [u8], U)>);
+
+    impl<'de, 'a, U> Visitor<'de> for BytesVisitor<'a, U>
+    where
+        'de: 'a,
+        U: Capacity,
+    {
+        type Value = Cow<'a, [u8], U>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a byte string")
+        }
+
+        fn visit_borrowed_bytes<E>(self, value: &'de [u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::borrowed(value))
+        }
+
+        fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::owned(value.to_vec()))
+        }
+
+        fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::owned(value))
+        }
+
+        fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::borrowed(value.as_bytes()))
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::owned(value.as_bytes().to_vec()))
+        }
+
+        fn visit_string<E>(self, value: alloc::string::String) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Cow::owned(value.into_bytes()))
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+
+            Ok(Cow::owned(bytes))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_derive::{Deserialize, Serialize};
@@ -134,6 +258,29 @@ mod tests {
         assert!(cow.is_owned());
     }
 
+    #[test]
+    fn lean_cow_de() {
+        use crate::lean::Cow;
+
+        #[derive(Serialize, Deserialize)]
+        struct Test<'a> {
+            #[serde(borrow)]
+            foo: Cow<'a, str>,
+            bytes: Cow<'a, [u8]>,
+        }
+
+        let json = r#"{"foo":"Hello","bytes":[1,2,3]}"#;
+        let test: Test = serde_json::from_str(json).unwrap();
+
+        assert_eq!(test.foo, "Hello");
+        assert_eq!(test.bytes, &[1, 2, 3][..]);
+        assert!(test.foo.is_borrowed());
+
+        let out = serde_json::to_string(&test).unwrap();
+
+        assert_eq!(json, out);
+    }
+
     #[test]
     fn wide_cow_direct_bytes() {
         use crate::Cow;
@@ -146,4 +293,45 @@ mod tests {
         // We need to stay generic over `[T]`, so no specialization for byte slices
         assert!(cow.is_owned());
     }
+
+    #[test]
+    fn wide_cow_bytes() {
+        use crate::Cow;
+
+        #[derive(Serialize, Deserialize)]
+        struct Test<'a> {
+            #[serde(borrow, with = "crate::serde::bytes")]
+            foo: Cow<'a, [u8]>,
+            #[serde(with = "crate::serde::bytes")]
+            bar: Cow<'a, [u8]>,
+        }
+
+        let test: Test = serde_json::from_str(r#"{"foo":"Hello","bar":[1,2,3]}"#).unwrap();
+
+        assert_eq!(test.foo, &b"Hello"[..]);
+        assert_eq!(test.bar, &[1, 2, 3][..]);
+
+        assert!(test.foo.is_borrowed());
+        assert!(test.bar.is_owned());
+
+        let out = serde_json::to_string(&test).unwrap();
+
+        assert_eq!(out, r#"{"foo":[72,101,108,108,111],"bar":[1,2,3]}"#);
+    }
+
+    #[test]
+    fn lean_cow_bytes() {
+        use crate::lean::Cow;
+
+        #[derive(Deserialize)]
+        struct Test<'a> {
+            #[serde(borrow, with = "crate::serde::bytes")]
+            foo: Cow<'a, [u8]>,
+        }
+
+        let test: Test = serde_json::from_str(r#"{"foo":"\tHello"}"#).unwrap();
+
+        assert_eq!(test.foo, &b"\tHello"[..]);
+        assert!(test.foo.is_owned());
+    }
 }
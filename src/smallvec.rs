@@ -0,0 +1,77 @@
+use smallvec::{Array, SmallVec};
+
+use crate::generic::{Capacity, Cow};
+
+impl<T, U> Cow<'_, [T], U>
+where
+    T: Clone,
+    U: Capacity,
+{
+    /// Clones the slice into a `SmallVec`, which stays on the stack if it fits.
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    /// use smallvec::SmallVec;
+    ///
+    /// let cow: Cow<[u8]> = Cow::borrowed(&[1, 2, 3]);
+    /// let small: SmallVec<[u8; 4]> = cow.to_smallvec();
+    ///
+    /// assert_eq!(&small[..], &[1, 2, 3]);
+    /// assert!(!small.spilled());
+    /// ```
+    #[inline]
+    pub fn to_smallvec<A>(&self) -> SmallVec<A>
+    where
+        A: Array<Item = T>,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+// A spilled `SmallVec` hands over its heap allocation, an inline one is moved into a
+// new allocation.
+impl<A, U> From<SmallVec<A>> for Cow<'_, [A::Item], U>
+where
+    A: Array,
+    A::Item: Clone,
+    U: Capacity,
+{
+    #[inline]
+    fn from(vec: SmallVec<A>) -> Self {
+        Cow::owned(vec.into_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smallvec::{smallvec, SmallVec};
+
+    #[test]
+    fn wide_cow_from_smallvec() {
+        use crate::Cow;
+
+        let vec: SmallVec<[u32; 2]> = smallvec![1, 2, 3];
+        let ptr = vec.as_ptr();
+        let cow = Cow::<[u32]>::from(vec);
+
+        assert_eq!(cow, &[1, 2, 3][..]);
+        assert_eq!((*cow).as_ptr(), ptr);
+
+        let vec: SmallVec<[u32; 4]> = smallvec![1, 2];
+        let cow = Cow::<[u32]>::from(vec);
+
+        assert_eq!(cow, &[1, 2][..]);
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn lean_cow_to_smallvec() {
+        use crate::lean::Cow;
+
+        let cow: Cow<[String]> = Cow::owned(vec!["foo".to_owned(), "bar".to_owned()]);
+        let small: SmallVec<[String; 1]> = cow.to_smallvec();
+
+        assert_eq!(&small[..], &*cow);
+        assert!(small.spilled());
+    }
+}
@@ -0,0 +1,106 @@
+use alloc::string::String;
+
+use smartstring::{SmartString, SmartStringMode};
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    /// Converts into a `SmartString`, inlining short strings. Longer owned strings are
+    /// handed over through `String`, which `SmartString` may still have to copy into
+    /// a buffer of its own.
+    ///
+    /// ```rust
+    /// use beef::Cow;
+    /// use smartstring::{Compact, SmartString};
+    ///
+    /// let cow: Cow<str> = Cow::borrowed("Hello");
+    /// let string: SmartString<Compact> = cow.into_smartstring();
+    ///
+    /// assert_eq!(string, "Hello");
+    /// assert!(string.is_inline());
+    /// ```
+    #[inline]
+    pub fn into_smartstring<M>(self) -> SmartString<M>
+    where
+        M: SmartStringMode,
+    {
+        match self.try_unwrap_borrowed() {
+            Ok(borrowed) => SmartString::from(borrowed),
+            Err(owned) => SmartString::from(owned.into_owned()),
+        }
+    }
+}
+
+impl<M, U> From<Cow<'_, str, U>> for SmartString<M>
+where
+    M: SmartStringMode,
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        cow.into_smartstring()
+    }
+}
+
+impl<M, U> From<SmartString<M>> for Cow<'_, str, U>
+where
+    M: SmartStringMode,
+    U: Capacity,
+{
+    #[inline]
+    fn from(string: SmartString<M>) -> Self {
+        Cow::owned(String::from(string))
+    }
+}
+
+impl<'a, M, U> From<&'a SmartString<M>> for Cow<'a, str, U>
+where
+    M: SmartStringMode,
+    U: Capacity,
+{
+    #[inline]
+    fn from(string: &'a SmartString<M>) -> Self {
+        Cow::borrowed(string.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smartstring::alias::String as SmartString;
+
+    const LONG: &str = "a string long enough to not be inlined";
+
+    #[test]
+    fn wide_cow_into_smartstring() {
+        use crate::Cow;
+
+        let string: SmartString = Cow::<str>::owned(LONG.to_owned()).into_smartstring();
+
+        assert_eq!(string, LONG);
+        assert!(!string.is_inline());
+
+        let string = SmartString::from(Cow::<str>::borrowed("foo"));
+
+        assert_eq!(string, "foo");
+        assert!(string.is_inline());
+    }
+
+    #[test]
+    fn lean_cow_from_smartstring() {
+        use crate::lean::Cow;
+
+        let cow = Cow::<str>::from(SmartString::from(LONG));
+
+        assert_eq!(cow, LONG);
+        assert!(cow.is_owned());
+
+        let string = SmartString::from("foo");
+        let cow = Cow::<str>::from(&string);
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_borrowed());
+    }
+}
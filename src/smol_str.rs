@@ -0,0 +1,68 @@
+use alloc::string::String;
+
+use smol_str::SmolStr;
+
+use crate::generic::{Capacity, Cow};
+
+// `SmolStr` keeps longer strings in an `Arc<str>`, so converting either way copies
+// anything that isn't inlined.
+impl<U> From<Cow<'_, str, U>> for SmolStr
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        SmolStr::new(&*cow)
+    }
+}
+
+impl<U> From<SmolStr> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(string: SmolStr) -> Self {
+        Cow::owned(String::from(string))
+    }
+}
+
+impl<'a, U> From<&'a SmolStr> for Cow<'a, str, U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(string: &'a SmolStr) -> Self {
+        Cow::borrowed(string.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smol_str::SmolStr;
+
+    #[test]
+    fn wide_cow_into_smol_str() {
+        use crate::Cow;
+
+        let string = SmolStr::from(Cow::<str>::owned("foo".to_owned()));
+
+        assert_eq!(string, "foo");
+        assert!(!string.is_heap_allocated());
+
+        let cow = Cow::<str>::from(string);
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn lean_cow_from_smol_str() {
+        use crate::lean::Cow;
+
+        let string = SmolStr::new("identifier");
+        let cow = Cow::<str>::from(&string);
+
+        assert_eq!(cow, "identifier");
+        assert!(cow.is_borrowed());
+    }
+}
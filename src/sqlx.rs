@@ -0,0 +1,129 @@
+use sqlx::database::Database;
+use sqlx::decode::Decode;
+use sqlx::encode::{Encode, IsNull};
+use sqlx::error::BoxDynError;
+use sqlx::types::Type;
+
+use crate::generic::{Capacity, Cow};
+use crate::traits::Beef;
+
+impl<T, U, DB> Type<DB> for Cow<'_, T, U>
+where
+    T: Beef + Type<DB> + ?Sized,
+    U: Capacity,
+    DB: Database,
+{
+    #[inline]
+    fn type_info() -> DB::TypeInfo {
+        T::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+impl<'q, T, U, DB> Encode<'q, DB> for Cow<'q, T, U>
+where
+    T: Beef + ?Sized,
+    &'q T: Encode<'q, DB>,
+    T::Owned: Encode<'q, DB>,
+    U: Capacity,
+    DB: Database,
+{
+    #[inline]
+    fn encode(self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        match self.try_unwrap_borrowed() {
+            Ok(borrowed) => borrowed.encode(buf),
+            Err(owned) => owned.into_owned().encode(buf),
+        }
+    }
+
+    // Owned data can't be borrowed for `'q`, so it has to be cloned, the same as the
+    // `std::borrow::Cow` impls of drivers that hold on to the arguments.
+    #[inline]
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        self.clone().encode(buf)
+    }
+}
+
+impl<'r, T, U, DB> Decode<'r, DB> for Cow<'r, T, U>
+where
+    T: Beef + ?Sized,
+    &'r T: Decode<'r, DB>,
+    U: Capacity,
+    DB: Database,
+{
+    #[inline]
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        <&'r T>::decode(value).map(Cow::borrowed)
+    }
+}
+
+#[cfg(all(test, feature = "sqlx_any"))]
+mod tests {
+    use sqlx::any::{Any, AnyArguments, AnyValue, AnyValueKind};
+    use sqlx::decode::Decode;
+    use sqlx::encode::{Encode, IsNull};
+    use sqlx::types::Type;
+    use sqlx::value::Value;
+    use std::borrow::Cow as StdCow;
+
+    #[test]
+    fn wide_cow_sqlx() {
+        use crate::Cow;
+
+        assert_eq!(
+            <Cow<str> as Type<Any>>::type_info(),
+            <str as Type<Any>>::type_info()
+        );
+
+        let mut args = AnyArguments::default();
+
+        let foo = Cow::<str>::borrowed("foo").encode(&mut args.values);
+        let bar = Cow::<str>::owned("bar".to_owned()).encode(&mut args.values);
+
+        assert!(matches!(foo, Ok(IsNull::No)));
+        assert!(matches!(bar, Ok(IsNull::No)));
+
+        match &args.values.0[..] {
+            [AnyValueKind::Text(StdCow::Borrowed("foo")), AnyValueKind::Text(bar)] => {
+                assert_eq!(bar, "bar");
+            }
+            values => panic!("unexpected arguments: {:?}", values),
+        }
+
+        let value = AnyValue {
+            kind: AnyValueKind::Text("foo".into()),
+        };
+        let cow = <Cow<str> as Decode<Any>>::decode(value.as_ref()).unwrap();
+
+        assert_eq!(cow, "foo");
+        assert!(cow.is_borrowed());
+    }
+
+    #[test]
+    fn lean_cow_sqlx() {
+        use crate::lean::Cow;
+
+        let mut args = AnyArguments::default();
+
+        let foo = Cow::<[u8]>::borrowed(b"foo").encode_by_ref(&mut args.values);
+
+        assert!(matches!(foo, Ok(IsNull::No)));
+
+        assert!(matches!(
+            &args.values.0[..],
+            [AnyValueKind::Blob(StdCow::Borrowed(b"foo"))]
+        ));
+
+        let value = AnyValue {
+            kind: AnyValueKind::Blob(b"bar".to_vec().into()),
+        };
+        let cow = <Cow<[u8]> as Decode<Any>>::decode(value.as_ref()).unwrap();
+
+        assert_eq!(cow, &b"bar"[..]);
+        assert!(cow.is_borrowed());
+    }
+}
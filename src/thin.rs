@@ -0,0 +1,336 @@
+//! Namespace containing the 2-word, `ThinVec` backed `Cow` for slices.
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+use thin_vec::ThinVec;
+
+/// Clone-on-write slice whose owned variant is a [`ThinVec`](https://docs.rs/thin-vec),
+/// which stores its length and capacity in the allocation itself.
+///
+/// This makes it 2 words wide, same as [`beef::lean::Cow`](../lean/type.Cow.html), but
+/// on all architectures and without limiting the length or capacity to 32 bits, at the
+/// cost of an extra indirection to read the length of owned data.
+///
+/// Like the other `Cow`s, it takes the borrowed slice type, so `Cow<[T]>` is spelled
+/// the same. The owned type is a `ThinVec<T>` rather than a `Vec<T>`, so `owned`,
+/// `into_owned` and `to_mut` are not interchangeable with theirs.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::thin::Cow;
+/// use std::mem::size_of;
+/// use thin_vec::thin_vec;
+///
+/// let borrowed: Cow<[u8]> = Cow::borrowed(b"Hello");
+/// let owned: Cow<[u8]> = Cow::owned(thin_vec![1, 2, 3]);
+///
+/// assert_eq!(borrowed.len(), 5);
+/// assert!(owned.is_owned());
+/// assert_eq!(size_of::<Cow<[u8]>>(), 2 * size_of::<usize>());
+/// ```
+pub struct Cow<'a, T: Slice + ?Sized>(Inner<'a, T::Item>);
+
+/// Shorthand for a `ThinVec` backed `Cow<[u8]>`.
+pub type Bytes<'a> = Cow<'a, [u8]>;
+
+/// Slices that can be stored in a [`Cow`](./struct.Cow.html).
+///
+/// This trait is sealed and implemented for `[T]`.
+pub trait Slice: internal::Sealed {
+    /// The element type.
+    type Item: Clone;
+}
+
+impl<T: Clone> Slice for [T] {
+    type Item = T;
+}
+
+mod internal {
+    pub trait Sealed {}
+
+    impl<T: Clone> Sealed for [T] {}
+}
+
+#[derive(Clone)]
+enum Inner<'a, T: Clone> {
+    Borrowed(&'a [T]),
+    Owned(ThinVec<T>),
+}
+
+impl<T> Cow<'_, [T]>
+where
+    T: Clone,
+{
+    /// Owned data.
+    ///
+    /// A `ThinVec` without capacity is stored as an empty borrow.
+    #[inline]
+    pub fn owned(val: ThinVec<T>) -> Self {
+        if val.capacity() == 0 {
+            Cow(Inner::Borrowed(&[]))
+        } else {
+            Cow(Inner::Owned(val))
+        }
+    }
+}
+
+impl<'a, T> Cow<'a, [T]>
+where
+    T: Clone,
+{
+    /// Borrowed data.
+    #[inline]
+    pub const fn borrowed(val: &'a [T]) -> Self {
+        Cow(Inner::Borrowed(val))
+    }
+
+    /// Extracts the owned data.
+    ///
+    /// Clones the data if it is not already owned.
+    #[inline]
+    pub fn into_owned(self) -> ThinVec<T> {
+        match self.0 {
+            Inner::Borrowed(val) => ThinVec::from(val),
+            Inner::Owned(val) => val,
+        }
+    }
+
+    /// Extracts borrowed data.
+    ///
+    /// Panics: If the data is owned.
+    #[inline]
+    pub fn unwrap_borrowed(self) -> &'a [T] {
+        match self.0 {
+            Inner::Borrowed(val) => val,
+            Inner::Owned(_) => panic!("Can not turn owned beef::thin::Cow into a borrowed value"),
+        }
+    }
+
+    /// Returns `true` if data is borrowed or had no capacity.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Inner::Borrowed(_))
+    }
+
+    /// Returns `true` if data is owned and has non-0 capacity.
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        matches!(self.0, Inner::Owned(_))
+    }
+
+    /// Acquires a mutable reference to the owned data, cloning it first if it is borrowed.
+    #[inline]
+    pub fn to_mut(&mut self) -> &mut ThinVec<T> {
+        if let Inner::Borrowed(val) = self.0 {
+            self.0 = Inner::Owned(ThinVec::from(val));
+        }
+
+        match self.0 {
+            Inner::Owned(ref mut val) => val,
+            Inner::Borrowed(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T> Deref for Cow<'_, [T]>
+where
+    T: Clone,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        match self.0 {
+            Inner::Borrowed(val) => val,
+            Inner::Owned(ref val) => val,
+        }
+    }
+}
+
+impl<T> AsRef<[T]> for Cow<'_, [T]>
+where
+    T: Clone,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Borrow<[T]> for Cow<'_, [T]>
+where
+    T: Clone,
+{
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Clone for Cow<'_, [T]>
+where
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Cow(self.0.clone())
+    }
+}
+
+impl<T> Default for Cow<'_, [T]>
+where
+    T: Clone,
+{
+    #[inline]
+    fn default() -> Self {
+        Cow::borrowed(&[])
+    }
+}
+
+impl<'a, T> From<&'a [T]> for Cow<'a, [T]>
+where
+    T: Clone,
+{
+    #[inline]
+    fn from(val: &'a [T]) -> Self {
+        Cow::borrowed(val)
+    }
+}
+
+impl<T> From<ThinVec<T>> for Cow<'_, [T]>
+where
+    T: Clone,
+{
+    #[inline]
+    fn from(val: ThinVec<T>) -> Self {
+        Cow::owned(val)
+    }
+}
+
+impl<T> From<Vec<T>> for Cow<'_, [T]>
+where
+    T: Clone,
+{
+    /// Moves the elements into a new `ThinVec`, since the two can't share an allocation.
+    #[inline]
+    fn from(val: Vec<T>) -> Self {
+        Cow::owned(ThinVec::from(val))
+    }
+}
+
+impl<T> Hash for Cow<'_, [T]>
+where
+    T: Clone + Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<T> PartialEq for Cow<'_, [T]>
+where
+    T: Clone + PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T> Eq for Cow<'_, [T]> where T: Clone + Eq {}
+
+impl<T> PartialEq<[T]> for Cow<'_, [T]>
+where
+    T: Clone + PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T> PartialEq<&[T]> for Cow<'_, [T]>
+where
+    T: Clone + PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &&[T]) -> bool {
+        **self == **other
+    }
+}
+
+impl<T> PartialEq<Vec<T>> for Cow<'_, [T]>
+where
+    T: Clone + PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        **self == **other
+    }
+}
+
+impl<T> PartialOrd for Cow<'_, [T]>
+where
+    T: Clone + PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T> Ord for Cow<'_, [T]>
+where
+    T: Clone + Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T> fmt::Debug for Cow<'_, [T]>
+where
+    T: Clone + fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cow;
+    use thin_vec::{thin_vec, ThinVec};
+
+    #[test]
+    fn borrowed_and_owned() {
+        let borrowed: Cow<[u8]> = Cow::borrowed(&[1, 2, 3]);
+        let owned: Cow<[u8]> = Cow::owned(thin_vec![1, 2, 3]);
+
+        assert_eq!(borrowed, owned);
+        assert!(borrowed.is_borrowed());
+        assert!(owned.is_owned());
+        assert!(Cow::<[u8]>::owned(ThinVec::new()).is_borrowed());
+        assert_eq!(owned.clone().into_owned(), thin_vec![1, 2, 3]);
+        assert_eq!(borrowed.unwrap_borrowed(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn to_mut() {
+        let mut cow: Cow<[String]> = Cow::borrowed(&[]);
+
+        cow.to_mut().push("foo".into());
+
+        assert_eq!(cow, vec![String::from("foo")]);
+        assert!(cow.is_owned());
+    }
+}
@@ -1,21 +1,400 @@
+use crate::generic::Cow;
 use crate::lean::internal::Lean;
 use crate::wide::internal::Wide;
+use alloc::borrow::{Cow as StdCow, ToOwned};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ffi::CStr;
+use core::mem;
 pub(crate) use internal::InternalBeef;
 pub(crate) use internal::InternalCapacity;
 
+/// Types that can be stored in a [`Cow`](./struct.Cow.html).
+///
+/// This trait is implemented for `str`, `[T]` and `CStr`, and can't be implemented
+/// directly. Other types implement it through [`Steak`](./trait.Steak.html) or
+/// [`Transparent`](./trait.Transparent.html) instead.
 pub trait Beef: InternalBeef {}
 impl<T: Clone> Beef for [T] {}
 impl Beef for str {}
+impl Beef for CStr {}
+#[cfg(feature = "std")]
+impl Beef for std::ffi::OsStr {}
+#[cfg(feature = "std")]
+impl Beef for std::path::Path {}
+impl<T: Steak + ?Sized> Beef for T {}
 
+/// A type that can be viewed as another [`Beef`](./trait.Beef.html) type, which
+/// makes it [`Beef`](./trait.Beef.html) as well, without any `unsafe` code.
+///
+/// The `Cow` stores the inner type, and converts to and from it on access. For
+/// `#[repr(transparent)]` newtypes, such as `struct Ident(str)`, implement
+/// [`Transparent`](./trait.Transparent.html) or use `#[derive(Beef)]` instead, which
+/// implement this trait with a pointer cast.
+///
+/// ```rust
+/// use beef::{Cow, Steak};
+/// use ref_cast::RefCast;
+/// use std::borrow::Borrow;
+///
+/// #[derive(RefCast, Debug, PartialEq)]
+/// #[repr(transparent)]
+/// struct Ident(str);
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct IdentBuf(String);
+///
+/// impl Borrow<Ident> for IdentBuf {
+///     fn borrow(&self) -> &Ident {
+///         Ident::ref_cast(&self.0)
+///     }
+/// }
+///
+/// impl ToOwned for Ident {
+///     type Owned = IdentBuf;
+///
+///     fn to_owned(&self) -> IdentBuf {
+///         IdentBuf(self.0.to_owned())
+///     }
+/// }
+///
+/// impl Steak for Ident {
+///     type Inner = str;
+///
+///     fn as_inner(&self) -> &str {
+///         &self.0
+///     }
+///
+///     fn from_inner_ref(inner: &str) -> &Ident {
+///         Ident::ref_cast(inner)
+///     }
+///
+///     fn into_inner_owned(owned: IdentBuf) -> String {
+///         owned.0
+///     }
+///
+///     fn from_inner_owned(owned: String) -> IdentBuf {
+///         IdentBuf(owned)
+///     }
+///
+///     // An empty `Ident` is valid, so `Cow::to_mut` doesn't need to clone owned data
+///     fn empty<'a>() -> Option<&'a Ident> {
+///         Some(Ident::ref_cast(""))
+///     }
+/// }
+///
+/// let ident: Cow<Ident> = Cow::borrowed(Ident::ref_cast("foo"));
+///
+/// assert!(ident.is_borrowed());
+/// assert_eq!(ident.into_owned(), IdentBuf("foo".to_string()));
+/// ```
+pub trait Steak: ToOwned {
+    /// The type stored in the `Cow`.
+    type Inner: Beef + ?Sized;
+
+    /// Views `self` as the inner type.
+    fn as_inner(&self) -> &Self::Inner;
+
+    /// Views the inner type as `Self`. This should be the inverse of `as_inner`.
+    fn from_inner_ref(inner: &Self::Inner) -> &Self;
+
+    /// Converts the owned type into the owned inner type.
+    fn into_inner_owned(owned: Self::Owned) -> <Self::Inner as ToOwned>::Owned;
+
+    /// Converts the owned inner type back into the owned type.
+    fn from_inner_owned(owned: <Self::Inner as ToOwned>::Owned) -> Self::Owned;
+
+    /// A borrowed value that `Cow::to_mut` can leave behind while it moves owned data
+    /// out, such as `""` for a wrapper around `str`. Without one, which is the default,
+    /// `Cow::to_mut` clones owned data on every call.
+    #[inline]
+    fn empty<'a>() -> Option<&'a Self> {
+        None
+    }
+}
+
+/// A `#[repr(transparent)]` wrapper around another [`Beef`](./trait.Beef.html) type,
+/// such as `struct Ident(str)`, which makes it [`Beef`](./trait.Beef.html) as well.
+///
+/// The borrowed wrapper is stored as the inner type, and its owned counterpart is
+/// converted to and from the owned inner type, e.g. `String`.
+///
+/// Rather than implementing this trait by hand, use `#[derive(Beef)]` from the `derive`
+/// feature, which checks the layout requirements and converts between the owned types
+/// with `From` and `Into`.
+///
+/// # Safety
+///
+/// `Self` must be `#[repr(transparent)]` over `Self::Inner`, so that pointers to the
+/// two can be cast into one another.
+pub unsafe trait Transparent: ToOwned {
+    /// The wrapped type.
+    type Inner: Beef + ?Sized;
+
+    /// Converts the owned wrapper into the owned inner type.
+    fn into_inner(owned: Self::Owned) -> <Self::Inner as ToOwned>::Owned;
+
+    /// Converts the owned inner type back into the owned wrapper.
+    fn from_inner(owned: <Self::Inner as ToOwned>::Owned) -> Self::Owned;
+
+    /// A borrowed value that `Cow::to_mut` can leave behind while it moves owned data
+    /// out, same as [`Steak::empty`](./trait.Steak.html#method.empty).
+    #[inline]
+    fn empty<'a>() -> Option<&'a Self> {
+        None
+    }
+}
+
+impl<T> Steak for T
+where
+    T: Transparent + ?Sized,
+{
+    type Inner = <T as Transparent>::Inner;
+
+    #[inline]
+    fn as_inner(&self) -> &Self::Inner {
+        // `Transparent` guarantees that `T` has the same layout and pointer metadata
+        unsafe { &*mem::transmute_copy::<*const T, *const Self::Inner>(&(self as *const T)) }
+    }
+
+    #[inline]
+    fn from_inner_ref(inner: &Self::Inner) -> &T {
+        unsafe { &*mem::transmute_copy::<*const Self::Inner, *const T>(&(inner as *const _)) }
+    }
+
+    #[inline]
+    fn into_inner_owned(owned: T::Owned) -> <Self::Inner as ToOwned>::Owned {
+        T::into_inner(owned)
+    }
+
+    #[inline]
+    fn from_inner_owned(owned: <Self::Inner as ToOwned>::Owned) -> T::Owned {
+        T::from_inner(owned)
+    }
+
+    #[inline]
+    fn empty<'a>() -> Option<&'a T> {
+        <T as Transparent>::empty()
+    }
+}
+
+/// Layout of the length and capacity fields of a [`Cow`](./struct.Cow.html).
+///
+/// This trait is sealed, use either [`beef::Cow`](../type.Cow.html) or [`beef::lean::Cow`](../lean/type.Cow.html).
 pub trait Capacity: InternalCapacity {}
 impl Capacity for Lean {}
 impl Capacity for Wide {}
 
+/// Conversion into a [`Cow`](./struct.Cow.html) of either flavor, without forcing an allocation.
+///
+/// Use this as a bound on arguments to accept anything that can become a `Cow<T>`:
+/// references, owned values, `std::borrow::Cow`s, and `beef` `Cow`s.
+///
+/// # Example
+///
+/// ```rust
+/// use beef::{Cow, IntoCow};
+///
+/// struct User {
+///     name: Cow<'static, str>,
+/// }
+///
+/// impl User {
+///     fn set_name(&mut self, name: impl IntoCow<'static, str>) {
+///         self.name = name.into_cow();
+///     }
+/// }
+///
+/// let mut user = User { name: Cow::borrowed("") };
+///
+/// user.set_name("Alice");
+/// assert!(user.name.is_borrowed());
+///
+/// user.set_name(String::from("Bob"));
+/// assert!(user.name.is_owned());
+///
+/// user.set_name(std::borrow::Cow::Borrowed("Carol"));
+/// assert_eq!(user.name, "Carol");
+/// ```
+pub trait IntoCow<'a, T: Beef + ?Sized> {
+    /// Converts this value into a `Cow`.
+    fn into_cow<U: Capacity>(self) -> Cow<'a, T, U>;
+}
+
+impl<'a, T> IntoCow<'a, T> for &'a T
+where
+    T: Beef + ?Sized,
+{
+    #[inline]
+    fn into_cow<U: Capacity>(self) -> Cow<'a, T, U> {
+        Cow::borrowed(self)
+    }
+}
+
+impl<'a> IntoCow<'a, str> for &'a String {
+    #[inline]
+    fn into_cow<U: Capacity>(self) -> Cow<'a, str, U> {
+        Cow::borrowed(self.as_str())
+    }
+}
+
+impl<'a, T: Clone> IntoCow<'a, [T]> for &'a Vec<T> {
+    #[inline]
+    fn into_cow<U: Capacity>(self) -> Cow<'a, [T], U> {
+        Cow::borrowed(self.as_slice())
+    }
+}
+
+impl<'a> IntoCow<'a, str> for String {
+    #[inline]
+    fn into_cow<U: Capacity>(self) -> Cow<'a, str, U> {
+        Cow::owned(self)
+    }
+}
+
+impl<'a, T: Clone> IntoCow<'a, [T]> for Vec<T> {
+    #[inline]
+    fn into_cow<U: Capacity>(self) -> Cow<'a, [T], U> {
+        Cow::owned(self)
+    }
+}
+
+impl<'a, T> IntoCow<'a, T> for StdCow<'a, T>
+where
+    T: Beef + ?Sized,
+{
+    #[inline]
+    fn into_cow<U: Capacity>(self) -> Cow<'a, T, U> {
+        Cow::from(self)
+    }
+}
+
+impl<'a, T, V> IntoCow<'a, T> for Cow<'a, T, V>
+where
+    T: Beef + ?Sized,
+    V: Capacity,
+{
+    #[inline]
+    fn into_cow<U: Capacity>(self) -> Cow<'a, T, U> {
+        Cow::from(StdCow::from(self))
+    }
+}
+
+/// Patterns accepted by [`Cow::split_once`](./struct.Cow.html#method.split_once) and
+/// [`Cow::rsplit_once`](./struct.Cow.html#method.rsplit_once).
+///
+/// This is a stable stand-in for `std::str::pattern::Pattern`, implemented for the
+/// same types: `char`, `&str`, `&String`, `&[char]`, `[char; N]`, `&&str` and
+/// `FnMut(char) -> bool` closures.
+pub trait SplitPattern {
+    /// Returns the byte range of the first match in `haystack`.
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the byte range of the last match in `haystack`.
+    fn rfind_in(self, haystack: &str) -> Option<(usize, usize)>;
+}
+
+impl SplitPattern for char {
+    #[inline]
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(self).map(|idx| (idx, idx + self.len_utf8()))
+    }
+
+    #[inline]
+    fn rfind_in(self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.rfind(self).map(|idx| (idx, idx + self.len_utf8()))
+    }
+}
+
+impl SplitPattern for &str {
+    #[inline]
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(self).map(|idx| (idx, idx + self.len()))
+    }
+
+    #[inline]
+    fn rfind_in(self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.rfind(self).map(|idx| (idx, idx + self.len()))
+    }
+}
+
+impl SplitPattern for &&str {
+    #[inline]
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        (*self).find_in(haystack)
+    }
+
+    #[inline]
+    fn rfind_in(self, haystack: &str) -> Option<(usize, usize)> {
+        (*self).rfind_in(haystack)
+    }
+}
+
+impl SplitPattern for &String {
+    #[inline]
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_str().find_in(haystack)
+    }
+
+    #[inline]
+    fn rfind_in(self, haystack: &str) -> Option<(usize, usize)> {
+        self.as_str().rfind_in(haystack)
+    }
+}
+
+impl SplitPattern for &[char] {
+    #[inline]
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        (|c| self.contains(&c)).find_in(haystack)
+    }
+
+    #[inline]
+    fn rfind_in(self, haystack: &str) -> Option<(usize, usize)> {
+        (|c| self.contains(&c)).rfind_in(haystack)
+    }
+}
+
+impl<const N: usize> SplitPattern for [char; N] {
+    #[inline]
+    fn find_in(self, haystack: &str) -> Option<(usize, usize)> {
+        (&self[..]).find_in(haystack)
+    }
+
+    #[inline]
+    fn rfind_in(self, haystack: &str) -> Option<(usize, usize)> {
+        (&self[..]).rfind_in(haystack)
+    }
+}
+
+impl<F> SplitPattern for F
+where
+    F: FnMut(char) -> bool,
+{
+    #[inline]
+    fn find_in(mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .find(|&(_, c)| self(c))
+            .map(|(idx, c)| (idx, idx + c.len_utf8()))
+    }
+
+    #[inline]
+    fn rfind_in(mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| self(c))
+            .map(|(idx, c)| (idx, idx + c.len_utf8()))
+    }
+}
+
 pub(crate) mod internal {
     use crate::generic::Capacity;
     use alloc::borrow::ToOwned;
+    use alloc::ffi::CString;
     use alloc::string::String;
     use alloc::vec::Vec;
+    use core::ffi::CStr;
     use core::mem::ManuallyDrop;
     use core::ptr::{slice_from_raw_parts, NonNull};
 
@@ -42,6 +421,11 @@ pub(crate) mod internal {
     /// + `T::Owned` has a `capacity`, which is an extra word that is absent in `T`.
     /// + `T::Owned` with `capacity` of `0` does not allocate memory.
     /// + `T::Owned` can be reconstructed from `*mut T` borrowed out of it, plus capacity.
+    ///
+    /// # Safety
+    ///
+    /// Implementors must guarantee that `owned_from_parts` rebuilds the exact
+    /// `T::Owned` that was taken apart by `owned_into_parts`.
     pub unsafe trait InternalBeef: ToOwned {
         type PointerT;
 
@@ -68,6 +452,17 @@ pub(crate) mod internal {
         ) -> Self::Owned
         where
             U: Capacity;
+
+        /// Parts of a valid, empty borrowed value, left behind in a `Cow` while its owned
+        /// data is moved out. `None` if there is no such value, in which case the owned
+        /// data is cloned instead.
+        #[inline]
+        fn empty_parts<U>() -> Option<(NonNull<Self::PointerT>, usize, U::Field)>
+        where
+            U: Capacity,
+        {
+            None
+        }
     }
 
     unsafe impl InternalBeef for str {
@@ -126,6 +521,14 @@ pub(crate) mod internal {
 
             String::from_utf8_unchecked(Vec::from_raw_parts(ptr.as_ptr(), len, cap))
         }
+
+        #[inline]
+        fn empty_parts<U>() -> Option<(NonNull<u8>, usize, U::Field)>
+        where
+            U: Capacity,
+        {
+            Some("".ref_into_parts::<U>())
+        }
     }
 
     unsafe impl<T: Clone> InternalBeef for [T] {
@@ -182,5 +585,273 @@ pub(crate) mod internal {
 
             Vec::from_raw_parts(ptr.as_ptr(), len, cap)
         }
+
+        #[inline]
+        fn empty_parts<U>() -> Option<(NonNull<T>, usize, U::Field)>
+        where
+            U: Capacity,
+        {
+            let empty: &[T] = &[];
+
+            Some(empty.ref_into_parts::<U>())
+        }
+    }
+
+    /// `CString` is a boxed slice, so its capacity is always equal to its length,
+    /// which includes the trailing NUL byte.
+    unsafe impl InternalBeef for CStr {
+        type PointerT = u8;
+
+        #[inline]
+        fn ref_into_parts<U>(&self) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            let (fat, cap) = U::empty(self.to_bytes_with_nul().len());
+
+            // A note on soundness:
+            //
+            // We are casting *const T to *mut T, however for all borrowed values
+            // this raw pointer is only ever dereferenced back to &T.
+            (
+                unsafe { NonNull::new_unchecked(self.as_ptr() as *mut u8) },
+                fat,
+                cap,
+            )
+        }
+
+        #[inline]
+        unsafe fn ref_from_parts<U>(ptr: NonNull<u8>, fat: usize) -> *const CStr
+        where
+            U: Capacity,
+        {
+            // The length includes the NUL byte, so it is never 0
+            debug_assert_ne!(U::len(fat), 0);
+
+            let bytes = &*slice_from_raw_parts(ptr.as_ptr(), U::len(fat));
+
+            CStr::from_bytes_with_nul_unchecked(bytes)
+        }
+
+        #[inline]
+        fn owned_into_parts<U>(owned: CString) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            // Length includes the NUL byte, and is never 0, so the `Cow` is always owned
+            let mut owned = ManuallyDrop::new(owned.into_bytes_with_nul());
+            let (fat, cap) = U::store(owned.len(), owned.capacity());
+
+            (
+                unsafe { NonNull::new_unchecked(owned.as_mut_ptr()) },
+                fat,
+                cap,
+            )
+        }
+
+        #[inline]
+        unsafe fn owned_from_parts<U>(ptr: NonNull<u8>, fat: usize, capacity: U::NonZero) -> CString
+        where
+            U: Capacity,
+        {
+            let (len, cap) = U::unpack(fat, capacity);
+
+            // The vector still ends with the NUL byte, and `len == cap` so no reallocation
+            // happens when `CString` turns it back into a boxed slice.
+            CString::from_vec_with_nul_unchecked(Vec::from_raw_parts(ptr.as_ptr(), len, cap))
+        }
+
+        /// Points at a static NUL byte, as `CStr` can't be empty.
+        #[inline]
+        fn empty_parts<U>() -> Option<(NonNull<u8>, usize, U::Field)>
+        where
+            U: Capacity,
+        {
+            let empty = unsafe { CStr::from_bytes_with_nul_unchecked(b"\0") };
+
+            Some(empty.ref_into_parts::<U>())
+        }
+    }
+
+    /// `OsString` is taken apart through its platform-independent encoded bytes, which
+    /// are a `Vec<u8>` on both Unix and Windows, so no reallocation happens either way.
+    #[cfg(feature = "std")]
+    unsafe impl InternalBeef for std::ffi::OsStr {
+        type PointerT = u8;
+
+        #[inline]
+        fn ref_into_parts<U>(&self) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            let bytes = self.as_encoded_bytes();
+            let (fat, cap) = U::empty(bytes.len());
+
+            // A note on soundness:
+            //
+            // We are casting *const T to *mut T, however for all borrowed values
+            // this raw pointer is only ever dereferenced back to &T.
+            (
+                unsafe { NonNull::new_unchecked(bytes.as_ptr() as *mut u8) },
+                fat,
+                cap,
+            )
+        }
+
+        #[inline]
+        unsafe fn ref_from_parts<U>(ptr: NonNull<u8>, fat: usize) -> *const std::ffi::OsStr
+        where
+            U: Capacity,
+        {
+            let bytes = &*slice_from_raw_parts(ptr.as_ptr(), U::len(fat));
+
+            std::ffi::OsStr::from_encoded_bytes_unchecked(bytes)
+        }
+
+        #[inline]
+        fn owned_into_parts<U>(owned: std::ffi::OsString) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            let mut owned = ManuallyDrop::new(owned.into_encoded_bytes());
+            let (fat, cap) = U::store(owned.len(), owned.capacity());
+
+            (
+                unsafe { NonNull::new_unchecked(owned.as_mut_ptr()) },
+                fat,
+                cap,
+            )
+        }
+
+        #[inline]
+        unsafe fn owned_from_parts<U>(
+            ptr: NonNull<u8>,
+            fat: usize,
+            capacity: U::NonZero,
+        ) -> std::ffi::OsString
+        where
+            U: Capacity,
+        {
+            let (len, cap) = U::unpack(fat, capacity);
+
+            std::ffi::OsString::from_encoded_bytes_unchecked(Vec::from_raw_parts(
+                ptr.as_ptr(),
+                len,
+                cap,
+            ))
+        }
+
+        #[inline]
+        fn empty_parts<U>() -> Option<(NonNull<u8>, usize, U::Field)>
+        where
+            U: Capacity,
+        {
+            Some(std::ffi::OsStr::new("").ref_into_parts::<U>())
+        }
+    }
+
+    /// `PathBuf` is a thin wrapper around `OsString`, and is taken apart the same way.
+    #[cfg(feature = "std")]
+    unsafe impl InternalBeef for std::path::Path {
+        type PointerT = u8;
+
+        #[inline]
+        fn ref_into_parts<U>(&self) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            self.as_os_str().ref_into_parts::<U>()
+        }
+
+        #[inline]
+        unsafe fn ref_from_parts<U>(ptr: NonNull<u8>, fat: usize) -> *const std::path::Path
+        where
+            U: Capacity,
+        {
+            std::path::Path::new(&*std::ffi::OsStr::ref_from_parts::<U>(ptr, fat))
+        }
+
+        #[inline]
+        fn owned_into_parts<U>(owned: std::path::PathBuf) -> (NonNull<u8>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            std::ffi::OsStr::owned_into_parts::<U>(owned.into_os_string())
+        }
+
+        #[inline]
+        unsafe fn owned_from_parts<U>(
+            ptr: NonNull<u8>,
+            fat: usize,
+            capacity: U::NonZero,
+        ) -> std::path::PathBuf
+        where
+            U: Capacity,
+        {
+            std::ffi::OsStr::owned_from_parts::<U>(ptr, fat, capacity).into()
+        }
+
+        #[inline]
+        fn empty_parts<U>() -> Option<(NonNull<u8>, usize, U::Field)>
+        where
+            U: Capacity,
+        {
+            std::ffi::OsStr::empty_parts::<U>()
+        }
+    }
+
+    /// An empty `T::Inner` is not necessarily a valid `T`, think `struct NonEmpty(str)`,
+    /// so only the value given by `Steak::empty` is left behind by `Cow::to_mut`.
+    unsafe impl<T> InternalBeef for T
+    where
+        T: super::Steak + ?Sized,
+    {
+        type PointerT = <T::Inner as InternalBeef>::PointerT;
+
+        #[inline]
+        fn ref_into_parts<U>(&self) -> (NonNull<Self::PointerT>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            self.as_inner().ref_into_parts::<U>()
+        }
+
+        #[inline]
+        unsafe fn ref_from_parts<U>(ptr: NonNull<Self::PointerT>, fat: usize) -> *const T
+        where
+            U: Capacity,
+        {
+            // `from_inner_ref` can only return a reference that lives at least as long as
+            // the inner one, which is all `Cow` needs.
+            T::from_inner_ref(&*T::Inner::ref_from_parts::<U>(ptr, fat))
+        }
+
+        #[inline]
+        fn owned_into_parts<U>(owned: T::Owned) -> (NonNull<Self::PointerT>, usize, U::Field)
+        where
+            U: Capacity,
+        {
+            T::Inner::owned_into_parts::<U>(T::into_inner_owned(owned))
+        }
+
+        #[inline]
+        unsafe fn owned_from_parts<U>(
+            ptr: NonNull<Self::PointerT>,
+            fat: usize,
+            capacity: U::NonZero,
+        ) -> T::Owned
+        where
+            U: Capacity,
+        {
+            T::from_inner_owned(T::Inner::owned_from_parts::<U>(ptr, fat, capacity))
+        }
+
+        #[inline]
+        fn empty_parts<U>() -> Option<(NonNull<Self::PointerT>, usize, U::Field)>
+        where
+            U: Capacity,
+        {
+            Some(T::empty()?.ref_into_parts::<U>())
+        }
     }
 }
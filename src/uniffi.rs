@@ -0,0 +1,80 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use uniffi_core::{derive_ffi_traits, FfiConverter, MetadataBuffer, Result, RustBuffer};
+
+use crate::generic::{Capacity, Cow};
+
+// `Cow`s cross the boundary as plain `String`s, foreign code never sees the
+// difference. Lifted values are always owned.
+unsafe impl<UT, U> FfiConverter<UT> for Cow<'static, str, U>
+where
+    U: Capacity,
+{
+    type FfiType = RustBuffer;
+
+    #[inline]
+    fn lower(obj: Self) -> RustBuffer {
+        <String as FfiConverter<UT>>::lower(obj.into_owned())
+    }
+
+    #[inline]
+    fn try_lift(v: RustBuffer) -> Result<Self> {
+        <String as FfiConverter<UT>>::try_lift(v).map(Cow::owned)
+    }
+
+    #[inline]
+    fn write(obj: Self, buf: &mut Vec<u8>) {
+        <String as FfiConverter<UT>>::write(obj.into_owned(), buf)
+    }
+
+    #[inline]
+    fn try_read(buf: &mut &[u8]) -> Result<Self> {
+        <String as FfiConverter<UT>>::try_read(buf).map(Cow::owned)
+    }
+
+    const TYPE_ID_META: MetadataBuffer = <String as FfiConverter<UT>>::TYPE_ID_META;
+}
+
+derive_ffi_traits!(impl<UT, U> Lower<UT> for Cow<'static, str, U> where U: Capacity);
+derive_ffi_traits!(impl<UT, U> Lift<UT> for Cow<'static, str, U> where U: Capacity);
+derive_ffi_traits!(impl<UT, U> LowerReturn<UT> for Cow<'static, str, U> where U: Capacity);
+derive_ffi_traits!(impl<UT, U> LiftReturn<UT> for Cow<'static, str, U> where U: Capacity);
+derive_ffi_traits!(impl<UT, U> LiftRef<UT> for Cow<'static, str, U> where U: Capacity);
+derive_ffi_traits!(impl<UT, U> TypeId<UT> for Cow<'static, str, U> where U: Capacity);
+
+#[cfg(test)]
+mod tests {
+    use uniffi_core::{FfiConverter, Lift, Lower, TypeId};
+
+    struct UniFfiTag;
+
+    #[test]
+    fn wide_cow_round_trip() {
+        use crate::Cow;
+
+        let buf = <Cow<str> as Lower<UniFfiTag>>::lower(Cow::borrowed("Hello"));
+        let cow = <Cow<str> as Lift<UniFfiTag>>::try_lift(buf).unwrap();
+
+        assert_eq!(cow, "Hello");
+        assert!(cow.is_owned());
+    }
+
+    #[test]
+    fn lean_cow_lifts_as_string() {
+        use crate::lean::Cow;
+
+        let mut buf = Vec::new();
+        <Cow<str> as Lower<UniFfiTag>>::write(Cow::owned("World".to_owned()), &mut buf);
+
+        let mut slice = &buf[..];
+        let cow = <Cow<str> as Lift<UniFfiTag>>::try_read(&mut slice).unwrap();
+
+        assert_eq!(cow, "World");
+        assert!(slice.is_empty());
+        assert_eq!(
+            <Cow<str> as TypeId<UniFfiTag>>::TYPE_ID_META.as_ref(),
+            <String as FfiConverter<UniFfiTag>>::TYPE_ID_META.as_ref(),
+        );
+    }
+}
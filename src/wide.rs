@@ -5,6 +5,12 @@ use core::num::NonZeroUsize;
 /// This is a type alias, for documentation see [`beef::generic::Cow`](./generic/struct.Cow.html).
 pub type Cow<'a, T> = crate::generic::Cow<'a, T, Wide>;
 
+/// Shorthand for a three word `Cow<str>`.
+pub type Str<'a> = Cow<'a, str>;
+
+/// Shorthand for a three word `Cow<[u8]>`.
+pub type Bytes<'a> = Cow<'a, [u8]>;
+
 pub(crate) mod internal {
     #[derive(Clone, Copy, PartialEq, Eq)]
     pub struct Wide;
@@ -0,0 +1,129 @@
+//! Conversions between `Cow`s and Windows strings.
+//!
+//! `Cow<str>` and `Cow<[u16]>` convert into `HSTRING`, while a `&HSTRING` converts
+//! into a `Cow<[u16]>` borrowing its UTF-16 contents without copying. The functions
+//! in this module read null-terminated `PCWSTR`s.
+//!
+//! ```rust
+//! use windows_strings::HSTRING;
+//! use beef::Cow;
+//!
+//! let hstring = HSTRING::from(Cow::<str>::borrowed("Hello"));
+//! let wide: Cow<[u16]> = Cow::from(&hstring);
+//!
+//! assert!(wide.is_borrowed());
+//! assert_eq!(HSTRING::from(wide), "Hello");
+//! ```
+
+use alloc::string::{FromUtf16Error, String};
+use core::convert::TryFrom;
+
+use windows_strings::{HSTRING, PCWSTR};
+
+use crate::generic::{Capacity, Cow};
+
+impl<U> From<Cow<'_, str, U>> for HSTRING
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, str, U>) -> Self {
+        HSTRING::from(&*cow)
+    }
+}
+
+impl<U> From<Cow<'_, [u16], U>> for HSTRING
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(cow: Cow<'_, [u16], U>) -> Self {
+        HSTRING::from_wide(&cow)
+    }
+}
+
+impl<'a, U> From<&'a HSTRING> for Cow<'a, [u16], U>
+where
+    U: Capacity,
+{
+    #[inline]
+    fn from(hstring: &'a HSTRING) -> Self {
+        Cow::borrowed(hstring)
+    }
+}
+
+// `HSTRING`s are UTF-16, so getting a `str` out of one always allocates.
+impl<U> TryFrom<&HSTRING> for Cow<'_, str, U>
+where
+    U: Capacity,
+{
+    type Error = FromUtf16Error;
+
+    #[inline]
+    fn try_from(hstring: &HSTRING) -> Result<Self, Self::Error> {
+        String::from_utf16(hstring).map(Cow::owned)
+    }
+}
+
+/// Borrow the UTF-16 contents of a `PCWSTR`, without the trailing null.
+///
+/// # Safety
+///
+/// The pointer must be valid for reads up to and including the next `\0`, and the
+/// data must not be modified or freed for the lifetime `'a`.
+#[inline]
+pub unsafe fn from_pcwstr<'a, U>(pcwstr: PCWSTR) -> Cow<'a, [u16], U>
+where
+    U: Capacity,
+{
+    Cow::borrowed(&*(pcwstr.as_wide() as *const [u16]))
+}
+
+/// Decode a `PCWSTR` into an owned `Cow<str>`.
+///
+/// # Safety
+///
+/// The pointer must be valid for reads up to and including the next `\0`.
+#[inline]
+pub unsafe fn pcwstr_to_str<U>(pcwstr: PCWSTR) -> Result<Cow<'static, str, U>, FromUtf16Error>
+where
+    U: Capacity,
+{
+    pcwstr.to_string().map(Cow::owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use windows_strings::{HSTRING, PCWSTR};
+
+    #[test]
+    fn wide_cow_into_hstring() {
+        use crate::Cow;
+
+        let hstring = HSTRING::from(Cow::<str>::borrowed("Hello"));
+        let wide: Cow<[u16]> = Cow::from(&hstring);
+
+        assert!(wide.is_borrowed());
+        assert_eq!(HSTRING::from(wide), hstring);
+
+        let cow = Cow::<str>::try_from(&hstring).unwrap();
+
+        assert_eq!(cow, "Hello");
+    }
+
+    #[test]
+    fn lean_cow_from_pcwstr() {
+        use crate::lean::Cow;
+
+        let hstring = HSTRING::from("World");
+        let wide: Cow<[u16]> = unsafe { super::from_pcwstr(PCWSTR(hstring.as_ptr())) };
+
+        assert_eq!(wide, &hstring[..]);
+        assert!(wide.is_borrowed());
+
+        let cow: Cow<str> = unsafe { super::pcwstr_to_str(PCWSTR(hstring.as_ptr())) }.unwrap();
+
+        assert_eq!(cow, "World");
+    }
+}
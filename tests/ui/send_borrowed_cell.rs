@@ -0,0 +1,8 @@
+use beef::lean::Cow;
+use std::cell::Cell;
+
+fn send<T: Send>(_: T) {}
+
+fn main() {
+    send(Cow::<[Cell<u8>]>::borrowed(&[]));
+}
@@ -0,0 +1,13 @@
+use beef::Cow;
+use std::marker::PhantomData;
+
+#[derive(Clone)]
+struct NotSend(PhantomData<*const ()>);
+
+unsafe impl Sync for NotSend {}
+
+fn send<T: Send>(_: T) {}
+
+fn main() {
+    send(Cow::<[NotSend]>::owned(vec![NotSend(PhantomData)]));
+}
@@ -0,0 +1,8 @@
+use beef::Cow;
+use std::cell::Cell;
+
+fn sync<T: Sync>(_: T) {}
+
+fn main() {
+    sync(Cow::<[Cell<u8>]>::borrowed(&[]));
+}